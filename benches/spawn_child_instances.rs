@@ -0,0 +1,50 @@
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::BuildChildren;
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use moonshine_kind::prelude::*;
+
+#[derive(Component)]
+struct Emitter;
+
+#[derive(Component)]
+struct Particle;
+
+fn spawn_child_instances(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spawn_child_instances");
+    for count in [10, 100, 1000] {
+        group.bench_with_input(BenchmarkId::new("batch", count), &count, |b, &count| {
+            b.iter_batched_ref(
+                setup,
+                |(world, emitter)| {
+                    world.spawn_child_instances(*emitter, (0..count).map(|_| Particle))
+                },
+                BatchSize::SmallInput,
+            );
+        });
+        group.bench_with_input(BenchmarkId::new("naive", count), &count, |b, &count| {
+            b.iter_batched_ref(
+                setup,
+                |(world, emitter)| {
+                    (0..count)
+                        .map(|_| {
+                            let particle = world.spawn_instance(Particle).instance();
+                            world.entity_mut(*emitter).add_child(particle.entity());
+                            particle
+                        })
+                        .collect::<Vec<_>>()
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
+fn setup() -> (World, Entity) {
+    let mut world = World::new();
+    let emitter = world.spawn_instance(Emitter).instance().entity();
+    (world, emitter)
+}
+
+criterion_group!(benches, spawn_child_instances);
+criterion_main!(benches);
@@ -87,12 +87,12 @@ fn human_eat(
     mut commands: Commands,
 ) {
     for (human, Eat(fruit)) in human.iter() {
-        if let Ok(apple) = apple.get(fruit.entity()) {
+        if let Some(apple) = fruit.downcast(&apple) {
             // Because of `kind!(Apple is Fruit);`, all apples can be cast into fruits:
             human_likes_fruit(human, apple.cast_into());
 
             println!("{human:?} ate a crunchy {apple:?}.");
-        } else if let Ok(orange) = orange.get(fruit.entity()) {
+        } else if let Some(orange) = fruit.downcast(&orange) {
             println!("{human:?} ate a juicy {orange:?}.");
         } else {
             println!("{human:?} ate a mysterious {fruit:?}.");
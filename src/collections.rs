@@ -0,0 +1,376 @@
+use std::{fmt, marker::PhantomData};
+
+use bevy_ecs::{
+    entity::{EntityHash, EntityHashMap, EntityHashSet},
+    prelude::*,
+    query::QueryFilter,
+};
+use bevy_utils::{Entry, Parallel};
+
+use crate::{Instance, Kind};
+
+/// A set of unique instances of [`Kind`] `T`.
+///
+/// # Usage
+/// This type behaves like an [`EntityHashSet`], except it is typed by kind.
+/// Only instances of kind `T` may be inserted into it, and all instances returned from it are of kind `T`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, InstanceSet};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Resource, Default)]
+/// struct Eaten(InstanceSet<Apple>);
+///
+/// fn eat_apples(apples: Query<Instance<Apple>>, mut eaten: ResMut<Eaten>) {
+///     for apple in apples.iter() {
+///         if eaten.0.insert(apple) {
+///             println!("{apple:?} was eaten!");
+///         }
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(eat_apples);
+/// ```
+pub struct InstanceSet<T: Kind>(EntityHashSet, PhantomData<T>);
+
+impl<T: Kind> InstanceSet<T> {
+    /// Creates a new empty [`InstanceSet<T>`].
+    pub fn new() -> Self {
+        Self(EntityHashSet::default(), PhantomData)
+    }
+
+    /// Creates a new empty [`InstanceSet<T>`] with at least the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(
+            EntityHashSet::with_capacity_and_hasher(capacity, Default::default()),
+            PhantomData,
+        )
+    }
+
+    /// Inserts the given [`Instance<T>`] into this set and returns `true` if it was not already present.
+    pub fn insert(&mut self, instance: Instance<T>) -> bool {
+        self.0.insert(instance.entity())
+    }
+
+    /// Removes the given [`Instance<T>`] from this set and returns `true` if it was present.
+    pub fn remove(&mut self, instance: Instance<T>) -> bool {
+        self.0.remove(&instance.entity())
+    }
+
+    /// Returns `true` if this set contains the given [`Instance<T>`].
+    pub fn contains(&self, instance: Instance<T>) -> bool {
+        self.0.contains(&instance.entity())
+    }
+
+    /// Returns the number of instances in this set.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if this set contains no instances.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Removes all instances from this set.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns an iterator over all instances in this set in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = Instance<T>> + '_ {
+        self.into_iter()
+    }
+
+    /// Removes all instances from this set and returns them as an iterator in arbitrary order.
+    ///
+    /// If the returned iterator is dropped before being fully consumed, the remaining instances are still removed.
+    pub fn drain(&mut self) -> impl Iterator<Item = Instance<T>> + '_ {
+        self.0.drain().map(instance_in_set)
+    }
+}
+
+impl<T: Kind> FromIterator<Instance<T>> for InstanceSet<T> {
+    fn from_iter<I: IntoIterator<Item = Instance<T>>>(iter: I) -> Self {
+        Self(
+            iter.into_iter().map(|instance| instance.entity()).collect(),
+            PhantomData,
+        )
+    }
+}
+
+impl<T: Kind> Extend<Instance<T>> for InstanceSet<T> {
+    fn extend<I: IntoIterator<Item = Instance<T>>>(&mut self, iter: I) {
+        self.0
+            .extend(iter.into_iter().map(|instance| instance.entity()));
+    }
+}
+
+impl<T: Kind> IntoIterator for InstanceSet<T> {
+    type Item = Instance<T>;
+    type IntoIter =
+        std::iter::Map<<EntityHashSet as IntoIterator>::IntoIter, fn(Entity) -> Instance<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter().map(instance_in_set)
+    }
+}
+
+impl<'a, T: Kind> IntoIterator for &'a InstanceSet<T> {
+    type Item = Instance<T>;
+    type IntoIter =
+        std::iter::Map<<&'a EntityHashSet as IntoIterator>::IntoIter, fn(&Entity) -> Instance<T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter().map(|&entity| instance_in_set(entity))
+    }
+}
+
+/// Returns the [`Instance<T>`] of an [`Entity`] stored in an [`InstanceSet<T>`].
+fn instance_in_set<T: Kind>(entity: Entity) -> Instance<T> {
+    // SAFE: Only instances of kind `T` may be inserted into an `InstanceSet<T>`.
+    unsafe { Instance::from_entity_unchecked(entity) }
+}
+
+impl<T: Kind> Default for InstanceSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Kind> Clone for InstanceSet<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: Kind> PartialEq for InstanceSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Kind> Eq for InstanceSet<T> {}
+
+/// Formats this set using [`Kind::debug_name`] (e.g. `InstanceSet<Fruit>[Fruit(1v1), Fruit(3v1)]`).
+impl<T: Kind> fmt::Debug for InstanceSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InstanceSet<{}>", T::debug_name())?;
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// A map of unique instances of [`Kind`] `T` to values of type `V`.
+///
+/// # Usage
+/// This type behaves like an [`EntityHashMap<V>`], except its keys are typed by kind.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, InstanceMap};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Resource, Default)]
+/// struct Bites(InstanceMap<Apple, u32>);
+///
+/// fn bite_apples(apples: Query<Instance<Apple>>, mut bites: ResMut<Bites>) {
+///     for apple in apples.iter() {
+///         *bites.0.entry(apple).or_default() += 1;
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(bite_apples);
+/// ```
+pub struct InstanceMap<T: Kind, V>(EntityHashMap<V>, PhantomData<T>);
+
+impl<T: Kind, V> InstanceMap<T, V> {
+    /// Creates a new empty [`InstanceMap<T, V>`].
+    pub fn new() -> Self {
+        Self(EntityHashMap::default(), PhantomData)
+    }
+
+    /// Creates a new empty [`InstanceMap<T, V>`] with at least the given capacity.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self(
+            EntityHashMap::with_capacity_and_hasher(capacity, Default::default()),
+            PhantomData,
+        )
+    }
+
+    /// Inserts a value for the given [`Instance<T>`] and returns the previous value, if any.
+    pub fn insert(&mut self, instance: Instance<T>, value: V) -> Option<V> {
+        self.0.insert(instance.entity(), value)
+    }
+
+    /// Removes the given [`Instance<T>`] from this map and returns its value, if any.
+    pub fn remove(&mut self, instance: Instance<T>) -> Option<V> {
+        self.0.remove(&instance.entity())
+    }
+
+    /// Returns a reference to the value of the given [`Instance<T>`], if any.
+    pub fn get(&self, instance: Instance<T>) -> Option<&V> {
+        self.0.get(&instance.entity())
+    }
+
+    /// Returns a mutable reference to the value of the given [`Instance<T>`], if any.
+    pub fn get_mut(&mut self, instance: Instance<T>) -> Option<&mut V> {
+        self.0.get_mut(&instance.entity())
+    }
+
+    /// Returns the [`Entry`] of the given [`Instance<T>`] for in-place manipulation.
+    pub fn entry(&mut self, instance: Instance<T>) -> Entry<'_, Entity, V, EntityHash> {
+        self.0.entry(instance.entity())
+    }
+
+    /// Returns `true` if this map contains a value for the given [`Instance<T>`].
+    pub fn contains_key(&self, instance: Instance<T>) -> bool {
+        self.0.contains_key(&instance.entity())
+    }
+
+    /// Returns the number of entries in this map.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns `true` if this map contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Removes all entries from this map.
+    pub fn clear(&mut self) {
+        self.0.clear();
+    }
+
+    /// Returns an iterator over all instances in this map in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = Instance<T>> + '_ {
+        self.iter().map(|(instance, _)| instance)
+    }
+
+    /// Returns an iterator over all values in this map in arbitrary order.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.0.values()
+    }
+
+    /// Returns an iterator over all entries in this map in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (Instance<T>, &V)> {
+        self.0.iter().map(|(&entity, value)| {
+            // SAFE: Only instances of kind `T` may be inserted into this map.
+            (unsafe { Instance::from_entity_unchecked(entity) }, value)
+        })
+    }
+}
+
+impl<T: Kind, V> Default for InstanceMap<T, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Kind, V: Clone> Clone for InstanceMap<T, V> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone(), PhantomData)
+    }
+}
+
+impl<T: Kind, V: PartialEq> PartialEq for InstanceMap<T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Kind, V: Eq> Eq for InstanceMap<T, V> {}
+
+/// Formats this map using [`Kind::debug_name`] (e.g. `InstanceMap<Fruit>{Fruit(1v1): 2, Fruit(3v1): 4}`).
+impl<T: Kind, V: fmt::Debug> fmt::Debug for InstanceMap<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InstanceMap<{}>", T::debug_name())?;
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Extension trait to collect all instances of a [`Query`] into an [`InstanceSet<T>`].
+pub trait CollectInstanceSet<T: Kind> {
+    /// Collects all instances of this query into an [`InstanceSet<T>`].
+    ///
+    /// # Usage
+    /// This is useful to take a snapshot of all current instances of a kind for deferred processing.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::{prelude::*, InstanceSet};
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Pending(InstanceSet<Apple>);
+    ///
+    /// fn collect_apples(apples: Query<Instance<Apple>>, mut pending: ResMut<Pending>) {
+    ///     pending.0 = apples.to_instance_set();
+    /// }
+    ///
+    /// fn process_apples(mut pending: ResMut<Pending>) {
+    ///     for apple in pending.0.drain() {
+    ///         println!("Processing {apple:?}");
+    ///     }
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(collect_apples);
+    /// # bevy_ecs::system::assert_is_system(process_apples);
+    /// ```
+    fn to_instance_set(&self) -> InstanceSet<T>;
+
+    /// Collects all instances of this query into an [`InstanceSet<T>`] using [`Query::par_iter`].
+    ///
+    /// # Usage
+    /// Each thread collects its instances into its own local buffer, so no locks are contended while iterating.
+    /// Once all threads are done, the local buffers are merged into a single set on the calling thread.
+    ///
+    /// This is useful when gathering many instances of a kind every frame.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// fn count_apples(apples: Query<Instance<Apple>>) {
+    ///     let apples = apples.collect_instances_par();
+    ///     println!("There are {} apples!", apples.len());
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(count_apples);
+    /// ```
+    fn collect_instances_par(&self) -> InstanceSet<T>;
+}
+
+impl<T: Kind, F: QueryFilter> CollectInstanceSet<T> for Query<'_, '_, Instance<T>, F> {
+    fn to_instance_set(&self) -> InstanceSet<T> {
+        self.iter().collect()
+    }
+
+    fn collect_instances_par(&self) -> InstanceSet<T> {
+        let mut locals = Parallel::<Vec<Entity>>::default();
+        self.par_iter().for_each(|instance| {
+            locals.borrow_local_mut().push(instance.entity());
+        });
+        let len = locals.iter_mut().map(|local| local.len()).sum();
+        let mut instances = InstanceSet::with_capacity(len);
+        for local in locals.iter_mut() {
+            instances.0.extend(local.drain(..));
+        }
+        instances
+    }
+}
@@ -0,0 +1,96 @@
+use bevy_ecs::{component::ComponentId, prelude::*};
+
+/// Represents an [`Entity`] of a kind which is defined by a [`ComponentId`] at runtime.
+///
+/// # Usage
+/// [`Kind`](crate::Kind) types are static, so they cannot refer to components which are registered at runtime
+/// (such as with [`World::register_component_with_descriptor`]). This type is an escape hatch for such cases,
+/// like data-driven or scripting setups, where the kind of an entity is only known by its [`ComponentId`].
+///
+/// A `DynInstance` is guaranteed to have contained its component when it was created.
+/// Use [`DynKindWorld`] to find and validate dynamic instances.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// let mut world = World::new();
+/// let apple = world.spawn(Apple).id();
+/// let id = world.component_id::<Apple>().unwrap();
+///
+/// let instance = world.get_dyn_instance(apple, id).unwrap();
+/// assert_eq!(instance.entity(), apple);
+/// assert!(world.contains_dyn_kind(apple, id));
+/// assert_eq!(world.iter_dyn_kind(id).collect::<Vec<_>>(), [apple]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DynInstance {
+    entity: Entity,
+    component_id: ComponentId,
+}
+
+impl DynInstance {
+    /// Creates a new [`DynInstance`] from an [`Entity`] and a [`ComponentId`] without any validation.
+    ///
+    /// # Safety
+    /// Assumes `entity` contains the component identified by `component_id`.
+    pub unsafe fn from_entity_unchecked(entity: Entity, component_id: ComponentId) -> Self {
+        Self {
+            entity,
+            component_id,
+        }
+    }
+
+    /// Returns the [`Entity`] of this instance.
+    pub fn entity(&self) -> Entity {
+        self.entity
+    }
+
+    /// Returns the [`ComponentId`] which defines the kind of this instance.
+    pub fn component_id(&self) -> ComponentId {
+        self.component_id
+    }
+}
+
+impl From<DynInstance> for Entity {
+    fn from(instance: DynInstance) -> Self {
+        instance.entity()
+    }
+}
+
+/// Extension trait to find and validate instances of runtime-defined kinds using [`World`].
+///
+/// See [`DynInstance`] for more information.
+pub trait DynKindWorld {
+    /// Returns an iterator over all entities which contain the component identified by `component_id`.
+    fn iter_dyn_kind(&self, component_id: ComponentId) -> impl Iterator<Item = Entity> + '_;
+
+    /// Returns `true` if the given [`Entity`] contains the component identified by `component_id`.
+    fn contains_dyn_kind(&self, entity: Entity, component_id: ComponentId) -> bool;
+
+    /// Returns a [`DynInstance`] for the given [`Entity`] if it contains the component identified by `component_id`.
+    fn get_dyn_instance(&self, entity: Entity, component_id: ComponentId) -> Option<DynInstance> {
+        self.contains_dyn_kind(entity, component_id).then(|| {
+            // SAFE: Entity is checked to contain the component.
+            unsafe { DynInstance::from_entity_unchecked(entity, component_id) }
+        })
+    }
+}
+
+impl DynKindWorld for World {
+    fn iter_dyn_kind(&self, component_id: ComponentId) -> impl Iterator<Item = Entity> + '_ {
+        self.archetypes()
+            .iter()
+            .filter(move |archetype| archetype.contains(component_id))
+            .flat_map(|archetype| archetype.entities().iter().map(|entity| entity.id()))
+    }
+
+    fn contains_dyn_kind(&self, entity: Entity, component_id: ComponentId) -> bool {
+        self.get_entity(entity)
+            .is_ok_and(|entity| entity.contains_id(component_id))
+    }
+}
@@ -0,0 +1,110 @@
+use bevy_app::App;
+use bevy_ecs::prelude::*;
+
+use crate::{Instance, Kind};
+
+/// A buffered [`Event`] of type `E` which targets an [`Instance<T>`].
+///
+/// # Usage
+/// This type is useful for reading all events which target instances of a [`Kind`] from a regular system,
+/// rather than observing each instance individually.
+///
+/// Use [`SendInstanceEvent::send_instance_event`] to send an instance event, and [`InstanceEventReader`]
+/// to read them. Instance events must be registered using [`AddInstanceEvent::add_instance_event`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// struct Bite;
+///
+/// fn bite_apples(apples: Query<Instance<Apple>>, mut commands: Commands) {
+///     for apple in apples.iter() {
+///         commands.send_instance_event::<Apple, _>(apple.entity(), Bite);
+///     }
+/// }
+///
+/// fn on_bite(mut reader: InstanceEventReader<Apple, Bite>) {
+///     for event in reader.read() {
+///         println!("{:?} was bitten!", event.instance);
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_instance_event::<Apple, Bite>()
+///     .add_systems(Update, (bite_apples, on_bite).chain());
+/// ```
+#[derive(Event)]
+pub struct InstanceEvent<T: Kind, E: Send + Sync + 'static> {
+    /// The target of this event.
+    pub instance: Instance<T>,
+    /// The event data.
+    pub event: E,
+}
+
+/// A [`SystemParam`](bevy_ecs::system::SystemParam) for reading [`InstanceEvent<T, E>`] events.
+pub type InstanceEventReader<'w, 's, T, E> = EventReader<'w, 's, InstanceEvent<T, E>>;
+
+/// Extension trait to register an [`InstanceEvent<T, E>`] with an [`App`].
+pub trait AddInstanceEvent {
+    /// Registers [`InstanceEvent<T, E>`] as a buffered event.
+    ///
+    /// See [`App::add_event`] for more information.
+    fn add_instance_event<T: Kind, E: Send + Sync + 'static>(&mut self) -> &mut Self;
+}
+
+impl AddInstanceEvent for App {
+    fn add_instance_event<T: Kind, E: Send + Sync + 'static>(&mut self) -> &mut Self {
+        self.add_event::<InstanceEvent<T, E>>()
+    }
+}
+
+/// Extension trait to send an [`InstanceEvent<T, E>`] using [`Commands`].
+pub trait SendInstanceEvent {
+    /// Sends an [`InstanceEvent<T, E>`] which targets the given [`Entity`].
+    ///
+    /// # Usage
+    /// The entity is resolved when this command is applied. If it does not exist, or is not of kind `T`,
+    /// no event is sent. This ensures every [`InstanceEvent<T, E>`] carries a valid [`Instance<T>`].
+    fn send_instance_event<T: Kind, E: Send + Sync + 'static>(&mut self, entity: Entity, event: E);
+}
+
+impl SendInstanceEvent for Commands<'_, '_> {
+    fn send_instance_event<T: Kind, E: Send + Sync + 'static>(&mut self, entity: Entity, event: E) {
+        self.queue(move |world: &mut World| {
+            world.send_instance_event::<T, E>(entity, event);
+        });
+    }
+}
+
+/// Extension trait to send an [`InstanceEvent<T, E>`] using [`World`].
+pub trait SendInstanceEventWorld {
+    /// Sends an [`InstanceEvent<T, E>`] which targets the given [`Entity`] and returns `true` if it was sent.
+    ///
+    /// See [`SendInstanceEvent::send_instance_event`] for more information.
+    fn send_instance_event<T: Kind, E: Send + Sync + 'static>(
+        &mut self,
+        entity: Entity,
+        event: E,
+    ) -> bool;
+}
+
+impl SendInstanceEventWorld for World {
+    fn send_instance_event<T: Kind, E: Send + Sync + 'static>(
+        &mut self,
+        entity: Entity,
+        event: E,
+    ) -> bool {
+        crate::register_kind_components::<T>(self);
+        if !crate::is_kind::<T>(self, entity) {
+            return false;
+        }
+        // SAFE: `entity` is checked to be of kind `T`.
+        let instance = unsafe { Instance::<T>::from_entity_unchecked(entity) };
+        self.send_event(InstanceEvent { instance, event }).is_some()
+    }
+}
@@ -9,16 +9,18 @@ use std::{
 use bevy_ecs::{
     archetype::Archetype,
     component::{ComponentId, Components, Tick},
-    entity::{EntityMapper, MapEntities},
+    entity::{EntityHashMap, EntityMapper, MapEntities},
     prelude::*,
-    query::{FilteredAccess, QueryData, ReadOnlyQueryData, WorldQuery},
+    query::{FilteredAccess, QueryData, QueryFilter, ReadOnlyQueryData, WorldQuery},
+    reflect::{AppTypeRegistry, ReflectComponent},
     storage::{Table, TableRow},
-    system::EntityCommands,
-    world::unsafe_world_cell::UnsafeWorldCell,
+    system::{EntityCommands, IntoObserverSystem},
+    world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld},
 };
+use bevy_hierarchy::BuildChildren;
 use bevy_reflect::Reflect;
 
-use crate::{Any, CastInto, Kind};
+use crate::{Any, CastInto, Kind, KindBundle, SameKind, SpawnInstance};
 
 /// Represents an [`Entity`] of [`Kind`] `T`.
 ///
@@ -65,6 +67,7 @@ use crate::{Any, CastInto, Kind};
 /// # bevy_ecs::system::assert_is_system(collect_fruits);
 /// ```
 #[derive(Reflect)]
+#[repr(transparent)]
 pub struct Instance<T: Kind>(Entity, #[reflect(ignore)] PhantomData<T>);
 
 impl<T: Kind> Instance<T> {
@@ -82,6 +85,8 @@ impl<T: Kind> Instance<T> {
     /// # Safety
     /// Assumes `entity` is a valid instance of kind `T`.
     ///
+    /// Since there is no world access, this assumption cannot be validated, even in debug builds.
+    ///
     /// # Example
     /// ```
     /// # use bevy::prelude::*;
@@ -100,11 +105,87 @@ impl<T: Kind> Instance<T> {
         Self(entity, PhantomData)
     }
 
+    /// Returns `true` if this instance and the given [`Instance<U>`] refer to the same [`Entity`].
+    ///
+    /// # Usage
+    /// Instances of different kinds may refer to the same entity, such as an `Instance<Apple>` and an `Instance<Fruit>`.
+    /// This function compares their identity, not their kind.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Orange;
+    ///
+    /// struct Fruit;
+    ///
+    /// impl Kind for Fruit {
+    ///     type Filter = Or<(With<Apple>, With<Orange>)>;
+    /// }
+    ///
+    /// fn is_same_fruit(apple: Instance<Apple>, fruit: Instance<Fruit>) -> bool {
+    ///     apple.same_entity(&fruit)
+    /// }
+    /// ```
+    pub fn same_entity<U: Kind>(&self, other: &Instance<U>) -> bool {
+        self.0 == other.0
+    }
+
+    /// Returns `true` if this instance is [`Instance::PLACEHOLDER`].
+    ///
+    /// # Usage
+    /// A placeholder instance does not refer to any valid entity. This may be used to detect
+    /// uninitialized instances before using them in any entity operation.
+    pub fn is_placeholder(&self) -> bool {
+        self.0 == Entity::PLACEHOLDER
+    }
+
     /// Returns the [`Entity`] of this instance.
+    ///
+    /// # Usage
+    /// An instance hashes and compares exactly like its entity. To use instances as keys in
+    /// entity-keyed collections (such as [`EntityHashMap`](bevy_ecs::entity::EntityHashMap)),
+    /// use this entity as the key. See [`InstanceSet`](crate::InstanceSet) and
+    /// [`InstanceMap`](crate::InstanceMap) for kind-typed alternatives.
     pub fn entity(&self) -> Entity {
         self.0
     }
 
+    /// Consumes this instance and returns its [`Entity`].
+    ///
+    /// This is equivalent to [`Instance::entity`] or [`Into<Entity>`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::entity::EntityHashMap;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// fn count_bites(apples: Query<Instance<Apple>>, bites: &mut EntityHashMap<u32>) {
+    ///     for apple in apples.iter() {
+    ///         *bites.entry(apple.into_entity()).or_default() += 1;
+    ///     }
+    /// }
+    /// ```
+    pub fn into_entity(self) -> Entity {
+        self.0
+    }
+
+    /// Returns the [`InstanceId<T>`] of this instance.
+    ///
+    /// See [`InstanceId`] for more information.
+    pub fn id(&self) -> InstanceId<T> {
+        InstanceId(self.0.to_bits(), PhantomData)
+    }
+
     /// Converts this instance into an instance of another kind [`Kind`] `U`.
     ///
     /// # Usage
@@ -118,6 +199,59 @@ impl<T: Kind> Instance<T> {
         T::cast_into(self)
     }
 
+    /// Converts a [`Vec`] of instances into a [`Vec`] of instances of [`Kind`] `U` without reallocation.
+    ///
+    /// # Usage
+    /// This is equivalent to calling [`Instance::cast_into`] on every element, but the original allocation is reused.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// struct Fruit;
+    ///
+    /// impl Kind for Fruit {
+    ///     type Filter = With<Apple>;
+    /// }
+    ///
+    /// kind!(Apple is Fruit);
+    ///
+    /// fn into_fruits(apples: Vec<Instance<Apple>>) -> Vec<Instance<Fruit>> {
+    ///     Instance::cast_vec(apples)
+    /// }
+    /// ```
+    pub fn cast_vec<U: Kind>(instances: Vec<Instance<T>>) -> Vec<Instance<U>>
+    where
+        T: CastInto<U>,
+    {
+        let mut instances = std::mem::ManuallyDrop::new(instances);
+        let (ptr, len, capacity) = (
+            instances.as_mut_ptr(),
+            instances.len(),
+            instances.capacity(),
+        );
+        // SAFE: `Instance<T>` and `Instance<U>` are both `repr(transparent)` over `Entity`, so they
+        // have identical size and alignment. `T: CastInto<U>` ensures every element is a valid `Instance<U>`.
+        unsafe { Vec::from_raw_parts(ptr.cast::<Instance<U>>(), len, capacity) }
+    }
+
+    /// Converts a slice of instances into a slice of instances of [`Kind`] `U` without copying.
+    ///
+    /// See [`Instance::cast_vec`] for more information.
+    pub fn cast_slice<U: Kind>(instances: &[Instance<T>]) -> &[Instance<U>]
+    where
+        T: CastInto<U>,
+    {
+        // SAFE: See `Instance::cast_vec`.
+        unsafe {
+            std::slice::from_raw_parts(instances.as_ptr().cast::<Instance<U>>(), instances.len())
+        }
+    }
+
     /// Converts this instance into an instance of [`Kind`] [`Any`].
     ///
     /// # Usage
@@ -128,6 +262,49 @@ impl<T: Kind> Instance<T> {
         unsafe { self.cast_into_unchecked() }
     }
 
+    /// Converts a slice of instances into a slice of instances of [`Kind`] [`Any`] without copying.
+    ///
+    /// # Usage
+    /// This is equivalent to calling [`Instance::cast_into_any`] on every element, but without any `CastInto` bound.
+    ///
+    /// There is no mutable variant of this function, because it would allow any entity to be written into
+    /// the original slice as an [`Instance<T>`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::{prelude::*, Any};
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// fn count(instances: &[Instance<Any>]) -> usize {
+    ///     instances.len()
+    /// }
+    ///
+    /// fn count_apples(apples: &[Instance<Apple>]) -> usize {
+    ///     count(Instance::as_any_slice(apples))
+    /// }
+    /// ```
+    pub fn as_any_slice(instances: &[Instance<T>]) -> &[Instance<Any>] {
+        // SAFE: `Instance<T>` and `Instance<Any>` are both `repr(transparent)` over `Entity`, so they
+        // have identical size and alignment. All instances are of kind `Any`.
+        unsafe {
+            std::slice::from_raw_parts(instances.as_ptr().cast::<Instance<Any>>(), instances.len())
+        }
+    }
+
+    /// Converts this instance into an instance of another kind [`Kind`] `U` with the same filter.
+    ///
+    /// # Usage
+    /// This conversion is always safe, since `T` and `U` match exactly the same entities.
+    ///
+    /// See [`SameKind`] for more information.
+    pub fn reinterpret<U: SameKind<T>>(self) -> Instance<U> {
+        // SAFE: `T` and `U` have the same filter.
+        unsafe { self.cast_into_unchecked() }
+    }
+
     /// Converts this instance into an instance of another kind [`Kind`] `U` without any validation.
     ///
     /// # Usage
@@ -138,9 +315,247 @@ impl<T: Kind> Instance<T> {
     ///
     /// # Safety
     /// Assumes this instance is also a valid `Instance<U>`.
+    ///
+    /// Since there is no world access, this assumption cannot be validated, even in debug builds.
     pub unsafe fn cast_into_unchecked<U: Kind>(self) -> Instance<U> {
         Instance::from_entity_unchecked(self.entity())
     }
+
+    /// Returns `true` if this instance is also of [`Kind`] `U` in the given [`World`].
+    ///
+    /// # Usage
+    /// This function evaluates `U::Filter` against the archetype of this instance's entity.
+    /// It is useful for branching logic and assertions where a full [`Query`] is not available.
+    ///
+    /// Note that only archetypal filters (such as [`With`], [`Without`] and [`Or`]) are evaluated.
+    /// Change detection filters (such as [`Added`] and [`Changed`]) are ignored.
+    ///
+    /// Returns `false` if the entity does not exist, or if any component referenced by `U::Filter`
+    /// is not registered in the world. Components are registered when first inserted into an entity,
+    /// or when first referenced by a [`Query`] or [`IsKindWorld::is_kind`](crate::IsKindWorld::is_kind).
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Orange;
+    ///
+    /// struct Fruit;
+    ///
+    /// impl Kind for Fruit {
+    ///     type Filter = Or<(With<Apple>, With<Orange>)>;
+    /// }
+    ///
+    /// fn is_apple(fruit: Instance<Fruit>, world: &World) -> bool {
+    ///     fruit.is_kind::<Apple>(world)
+    /// }
+    /// ```
+    pub fn is_kind<U: Kind>(self, world: &World) -> bool {
+        crate::is_kind::<U>(world, self.entity())
+    }
+
+    /// Returns this instance as an [`Instance<U>`] if it matches the given [`Query`].
+    ///
+    /// # Usage
+    /// This is useful to "downcast" an instance of a broader kind into a narrower kind.
+    ///
+    /// See [`Instance::downcast_world`] if a [`Query`] is not available.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Orange;
+    ///
+    /// struct Fruit;
+    ///
+    /// impl Kind for Fruit {
+    ///     type Filter = Or<(With<Apple>, With<Orange>)>;
+    /// }
+    ///
+    /// fn apples(fruits: Query<Instance<Fruit>>, apples: Query<Instance<Apple>>) {
+    ///     for fruit in fruits.iter() {
+    ///         if let Some(apple) = fruit.downcast(&apples) {
+    ///             println!("{fruit:?} is {apple:?}!");
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(apples);
+    /// ```
+    pub fn downcast<U: Kind, F: QueryFilter>(
+        self,
+        query: &Query<Instance<U>, F>,
+    ) -> Option<Instance<U>> {
+        query.get(self.entity()).ok()
+    }
+
+    /// Returns this instance as an [`Instance<U>`] if it is also of [`Kind`] `U` in the given [`World`].
+    ///
+    /// See [`Instance::is_kind`] for details on how `U::Filter` is evaluated.
+    pub fn downcast_world<U: Kind>(self, world: &World) -> Option<Instance<U>> {
+        // SAFE: Entity is checked to be of kind `U`.
+        self.is_kind::<U>(world)
+            .then(|| unsafe { self.cast_into_unchecked() })
+    }
+
+    /// Returns all [`Children`](bevy_hierarchy::Children) of this instance which are of [`Kind`] `C`, in order.
+    ///
+    /// # Usage
+    /// This is useful for hierarchies where a parent kind owns children of a specific kind.
+    /// If this instance does not exist or has no children, the result is empty.
+    ///
+    /// See [`Instance::is_kind`] for details on how `C::Filter` is evaluated.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Tree;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// fn count_apples(tree: Instance<Tree>, world: &World) -> usize {
+    ///     tree.children_of_kind::<Apple>(world).len()
+    /// }
+    /// ```
+    pub fn children_of_kind<C: Kind>(self, world: &World) -> Vec<Instance<C>> {
+        let Some(children) = world.get::<bevy_hierarchy::Children>(self.entity()) else {
+            return Vec::new();
+        };
+        let matches = crate::kind_matcher::<C>(world);
+        children
+            .iter()
+            .filter(|&&child| matches(child))
+            // SAFE: Entity is checked to be of kind `C`.
+            .map(|&child| unsafe { Instance::from_entity_unchecked(child) })
+            .collect()
+    }
+
+    /// Splits the given instances into those which are also of [`Kind`] `U`, and the rest.
+    ///
+    /// # Usage
+    /// This is useful to sort instances of a broad kind into narrower kinds, such as for tagging or bucketing.
+    /// The order of instances is preserved in both results.
+    ///
+    /// See [`Instance::is_kind`] for details on how `U::Filter` is evaluated.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Orange;
+    ///
+    /// struct Fruit;
+    ///
+    /// impl Kind for Fruit {
+    ///     type Filter = Or<(With<Apple>, With<Orange>)>;
+    /// }
+    ///
+    /// fn apples(fruits: &[Instance<Fruit>], world: &World) -> Vec<Instance<Apple>> {
+    ///     let (apples, _others) = Instance::partition_by_kind::<Apple>(fruits, world);
+    ///     apples
+    /// }
+    /// ```
+    pub fn partition_by_kind<U: Kind>(
+        instances: &[Instance<T>],
+        world: &World,
+    ) -> (Vec<Instance<U>>, Vec<Instance<T>>) {
+        let matches = crate::kind_matcher::<U>(world);
+        let mut matched = Vec::new();
+        let mut rest = Vec::new();
+        for &instance in instances {
+            if matches(instance.entity()) {
+                // SAFE: Entity is checked to be of kind `U`.
+                matched.push(unsafe { instance.cast_into_unchecked() });
+            } else {
+                rest.push(instance);
+            }
+        }
+        (matched, rest)
+    }
+}
+
+impl Instance<Any> {
+    /// Returns this instance as an [`Instance<T>`] if it is of [`Kind`] `T` in the given [`World`].
+    ///
+    /// # Usage
+    /// [`Instance<Any>`] is the common type-erased form of an instance. Upcasting any [`Instance<T>`] into it
+    /// using [`Instance::cast_into_any`] is always safe, since every entity is of kind [`Any`].
+    /// Narrowing it back down requires validation, which is what this function does.
+    ///
+    /// See [`Instance::is_kind`] for details on how `T::Filter` is evaluated.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Orange;
+    ///
+    /// let mut world = World::new();
+    /// let apple = world.spawn_instance(Apple).instance().cast_into_any();
+    /// assert!(apple.cast_checked::<Apple>(&world).is_some());
+    /// assert!(apple.cast_checked::<Orange>(&world).is_none());
+    /// ```
+    pub fn cast_checked<T: Kind>(self, world: &World) -> Option<Instance<T>> {
+        self.downcast_world(world)
+    }
+
+    /// Returns this instance as an [`Instance<T>`] if the given [`EntityRef`] of this instance contains a [`Component`] of type `T`.
+    ///
+    /// # Usage
+    /// This is a lightweight alternative to [`Instance::cast_checked`] when an [`EntityRef`] is already available,
+    /// such as when iterating over [`World::iter_entities`].
+    ///
+    /// Returns `None` if the given [`EntityRef`] does not refer to this instance.
+    ///
+    /// Note that a bare `TryFrom<Instance<Any>>` for `Instance<T>` is not feasible, because validating
+    /// the kind of an entity always requires access to its components.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::{prelude::*, Any};
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// fn apples(world: &World) -> Vec<Instance<Apple>> {
+    ///     world
+    ///         .iter_entities()
+    ///         .filter_map(|entity| Instance::<Any>::from(entity.id()).try_narrow(entity))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn try_narrow<T: Component>(self, entity: EntityRef) -> Option<Instance<T>> {
+        if entity.id() != self.entity() {
+            return None;
+        }
+        Instance::from_entity(entity)
+    }
 }
 
 impl<T: Component> Instance<T> {
@@ -153,6 +568,27 @@ impl<T: Component> Instance<T> {
             None
         }
     }
+
+    /// Returns the [`ComponentId`] of the [`Component`] which defines kind `T`, if it is registered in the given [`World`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// let mut world = World::new();
+    /// assert!(Instance::<Apple>::component_id(&world).is_none());
+    ///
+    /// let apple = world.spawn_instance(Apple).instance();
+    /// let id = Instance::<Apple>::component_id(&world).unwrap();
+    /// assert!(world.entity(apple.entity()).get_by_id(id).is_ok());
+    /// ```
+    pub fn component_id(world: &World) -> Option<ComponentId> {
+        world.component_id::<T>()
+    }
 }
 
 impl<T: Kind> Clone for Instance<T> {
@@ -163,15 +599,35 @@ impl<T: Kind> Clone for Instance<T> {
 
 impl<T: Kind> Copy for Instance<T> {}
 
+/// Generates an arbitrary instance of [`Kind`] `T` for fuzzing and property-based testing.
+///
+/// The generated entity is not validated in any way, and is intentionally not guaranteed to be of kind `T`,
+/// or to exist in any world. This allows edge cases such as placeholders and high generations to be explored.
+#[cfg(feature = "arbitrary")]
+impl<'a, T: Kind> arbitrary::Arbitrary<'a> for Instance<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let entity =
+            Entity::try_from_bits(u.arbitrary()?).map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        // SAFE: Arbitrary instances are intentionally unchecked.
+        Ok(unsafe { Self::from_entity_unchecked(entity) })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <u64 as arbitrary::Arbitrary>::size_hint(depth)
+    }
+}
+
+/// Formats this instance using [`Kind::debug_name`] (e.g. `Apple(12v1)`).
+///
+/// Use the alternate form (`{:#?}`) to format it using [`Kind::type_path`] instead.
 impl<T: Kind> fmt::Debug for Instance<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{}({}v{})",
-            T::debug_name(),
-            self.0.index(),
-            self.0.generation()
-        )
+        let (index, generation) = (self.0.index(), self.0.generation());
+        if f.alternate() {
+            write!(f, "{}({index}v{generation})", T::type_path())
+        } else {
+            write!(f, "{}({index}v{generation})", T::debug_name())
+        }
     }
 }
 
@@ -242,11 +698,12 @@ unsafe impl<T: Kind> WorldQuery for Instance<T> {
     const IS_DENSE: bool = <T::Filter as WorldQuery>::IS_DENSE;
 
     unsafe fn set_archetype<'w>(
-        _fetch: &mut Self::Fetch<'w>,
-        _state: &Self::State,
-        _archetype: &'w Archetype,
-        _table: &'w Table,
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
     ) {
+        <T::Filter as WorldQuery>::set_archetype(fetch, state, archetype, table)
     }
 
     unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
@@ -287,17 +744,300 @@ unsafe impl<T: Kind> QueryData for Instance<T> {
     type ReadOnly = Self;
 }
 
-impl<T: Kind> MapEntities for Instance<T> {
-    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
-        self.0 = entity_mapper.map_entity(self.0);
-    }
-}
-
-impl<T: Kind> From<Instance<T>> for Entity {
-    fn from(instance: Instance<T>) -> Self {
-        instance.entity()
-    }
-}
+/// An [`Instance<T>`] may also be used as a [`QueryFilter`], which is equivalent to `T::Filter`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Orange;
+///
+/// struct Fruit;
+///
+/// impl Kind for Fruit {
+///     type Filter = Or<(With<Apple>, With<Orange>)>;
+/// }
+///
+/// fn fruit_names(query: Query<&Name, Instance<Fruit>>) {
+///     for name in query.iter() {
+///         println!("{name} is a fruit!");
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(fruit_names);
+/// ```
+unsafe impl<T: Kind> QueryFilter for Instance<T> {
+    const IS_ARCHETYPAL: bool = <T::Filter as QueryFilter>::IS_ARCHETYPAL;
+
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        <T::Filter as QueryFilter>::filter_fetch(fetch, entity, table_row)
+    }
+}
+
+impl<T: Kind> MapEntities for Instance<T> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.0 = entity_mapper.map_entity(self.0);
+    }
+}
+
+/// A stable key which identifies an [`Instance<T>`] across frames.
+///
+/// # Usage
+/// Entities may be despawned and their index reused with a new generation.
+/// An `InstanceId<T>` encodes both the index and generation of an instance, so it behaves like a
+/// weak reference: [`InstanceId::resolve`] returns `None` if the original instance no longer exists.
+///
+/// This is useful for tracking instances in external systems which outlive the world, or which
+/// must not hold on to entities directly.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// let mut world = World::new();
+/// let apple = world.spawn_instance(Apple).instance();
+/// let id = apple.id();
+/// assert_eq!(id.resolve(&world), Some(apple));
+///
+/// world.despawn(apple.entity());
+/// assert_eq!(id.resolve(&world), None);
+/// ```
+pub struct InstanceId<T: Kind>(u64, PhantomData<T>);
+
+impl<T: Kind> InstanceId<T> {
+    /// Creates a new [`InstanceId<T>`] from its bit representation.
+    ///
+    /// This is safe because the instance is validated when [resolved](InstanceId::resolve).
+    pub fn from_bits(bits: u64) -> Self {
+        Self(bits, PhantomData)
+    }
+
+    /// Returns the bit representation of this [`InstanceId<T>`].
+    pub fn to_bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the [`Instance<T>`] identified by this key, if it still exists in the given [`World`] and is of kind `T`.
+    pub fn resolve(self, world: &World) -> Option<Instance<T>> {
+        let entity = Entity::try_from_bits(self.0).ok()?;
+        if !crate::is_kind::<T>(world, entity) {
+            return None;
+        }
+        // SAFE: Entity is checked to be of kind `T`.
+        Some(unsafe { Instance::from_entity_unchecked(entity) })
+    }
+}
+
+impl<T: Kind> Clone for InstanceId<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Kind> Copy for InstanceId<T> {}
+
+impl<T: Kind> Hash for InstanceId<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<T: Kind> PartialEq for InstanceId<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: Kind> Eq for InstanceId<T> {}
+
+/// Formats this key using [`Kind::debug_name`] and its bits (e.g. `InstanceId<Apple>(4294967308)`).
+impl<T: Kind> fmt::Debug for InstanceId<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "InstanceId<{}>({})", T::debug_name(), self.0)
+    }
+}
+
+/// Maps all instances in the given collection using an [`EntityMapper`].
+///
+/// # Usage
+/// [`Instance<T>`] implements [`MapEntities`], but collections of instances do not.
+/// This function may be used to implement [`MapEntities`] for components which contain
+/// collections of instances, such as a [`Vec`], an array or an [`Option`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::entity::{EntityMapper, MapEntities};
+/// # use moonshine_kind::{prelude::*, map_instances};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Basket {
+///     apples: Vec<Instance<Apple>>,
+///     best: Option<Instance<Apple>>,
+/// }
+///
+/// impl MapEntities for Basket {
+///     fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+///         map_instances(&mut self.apples, entity_mapper);
+///         map_instances(&mut self.best, entity_mapper);
+///     }
+/// }
+/// ```
+pub fn map_instances<'a, T: Kind>(
+    instances: impl IntoIterator<Item = &'a mut Instance<T>>,
+    entity_mapper: &mut impl EntityMapper,
+) {
+    for instance in instances {
+        instance.map_entities(entity_mapper);
+    }
+}
+
+/// Determines how an [`InstanceMapper`] maps entities which are not part of its entity map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UnmappedPolicy {
+    /// Unmapped entities are kept as-is.
+    ///
+    /// This is useful when the referenced entity is not cloned, but still exists in the same world.
+    #[default]
+    Preserve,
+    /// Unmapped entities are replaced with [`Entity::PLACEHOLDER`].
+    ///
+    /// This ensures no instance refers to an entity which was not part of the mapped set.
+    /// Use [`Instance::is_placeholder`] to check for such instances.
+    Placeholder,
+}
+
+/// An [`EntityMapper`] which maps instances using an [`EntityHashMap`] and an [`UnmappedPolicy`].
+///
+/// # Usage
+/// When cloning a set of entities, any [`Instance<T>`] which refers to another entity in the set should be
+/// remapped to refer to its clone. However, an instance may also refer to an entity which is not part of the set.
+/// By default, [`EntityMapper`] implementations (such as the one used for scenes) map such entities to
+/// entities which never exist, which leaves dangling instances.
+///
+/// This mapper allows choosing whether such instances are preserved or replaced with [`Instance::PLACEHOLDER`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::entity::{EntityHashMap, MapEntities};
+/// # use moonshine_kind::{prelude::*, InstanceMapper, UnmappedPolicy};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Target(Instance<Apple>);
+///
+/// impl MapEntities for Target {
+///     fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+///         self.0.map_entities(entity_mapper);
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// let apple = world.spawn_instance(Apple).instance();
+///
+/// // `apple` is not part of the map:
+/// let map = EntityHashMap::default();
+/// let mut target = Target(apple);
+/// target.map_entities(&mut InstanceMapper::new(&map, UnmappedPolicy::Placeholder));
+/// assert!(target.0.is_placeholder());
+/// ```
+pub struct InstanceMapper<'m> {
+    map: &'m EntityHashMap<Entity>,
+    policy: UnmappedPolicy,
+}
+
+impl<'m> InstanceMapper<'m> {
+    /// Creates a new [`InstanceMapper`] from an entity map and an [`UnmappedPolicy`].
+    pub fn new(map: &'m EntityHashMap<Entity>, policy: UnmappedPolicy) -> Self {
+        Self { map, policy }
+    }
+
+    /// Returns the [`UnmappedPolicy`] of this mapper.
+    pub fn policy(&self) -> UnmappedPolicy {
+        self.policy
+    }
+}
+
+impl EntityMapper for InstanceMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        if let Some(&mapped) = self.map.get(&entity) {
+            return mapped;
+        }
+        match self.policy {
+            UnmappedPolicy::Preserve => entity,
+            UnmappedPolicy::Placeholder => Entity::PLACEHOLDER,
+        }
+    }
+}
+
+/// Extension trait to convert an [`Iterator`] of [`Entity`] into an iterator of [`Instance<T>`].
+///
+/// # Usage
+/// This is useful for utility code which produces bare entities (such as [`Children`](bevy_hierarchy::Children))
+/// that should be treated as instances of some [`Kind`] without going through a [`Query`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// let mut world = World::new();
+/// let apple = world.spawn(Apple).id();
+/// let other = world.spawn_empty().id();
+///
+/// let apples: Vec<Instance<Apple>> = [apple, other].into_iter().instances_of(&world).collect();
+/// assert_eq!(apples.len(), 1);
+/// assert_eq!(apples[0].entity(), apple);
+/// ```
+pub trait InstancesOf: Iterator<Item = Entity> + Sized {
+    /// Returns an iterator over all entities in this iterator which are instances of [`Kind`] `T`.
+    ///
+    /// Entities which do not exist or do not match the filter of [`Kind`] `T` are skipped.
+    fn instances_of<T: Kind>(self, world: &World) -> impl Iterator<Item = Instance<T>> {
+        let matches = crate::kind_matcher::<T>(world);
+        self.filter(move |&entity| matches(entity))
+            // SAFE: Entity is checked to match the filter of kind `T`.
+            .map(|entity| unsafe { Instance::from_entity_unchecked(entity) })
+    }
+
+    /// Returns an iterator over all entities in this iterator as instances of [`Kind`] `T`.
+    ///
+    /// # Safety
+    /// Assumes all entities in this iterator are valid instances of kind `T`.
+    unsafe fn instances_of_unchecked<T: Kind>(self) -> impl Iterator<Item = Instance<T>> {
+        self.map(|entity| Instance::from_entity_unchecked(entity))
+    }
+}
+
+impl<I: Iterator<Item = Entity>> InstancesOf for I {}
+
+impl<T: Kind> From<Instance<T>> for Entity {
+    fn from(instance: Instance<T>) -> Self {
+        instance.entity()
+    }
+}
 
 impl From<Entity> for Instance<Any> {
     fn from(entity: Entity) -> Self {
@@ -305,6 +1045,98 @@ impl From<Entity> for Instance<Any> {
     }
 }
 
+/// A trait for any type which contains an [`Instance<T>`].
+///
+/// # Usage
+/// This trait is implemented for [`Instance<T>`] and all instance wrappers, such as [`InstanceRef<T>`],
+/// [`InstanceMut<T>`], [`InstanceCommands<T>`] and [`InstanceWorldMut<T>`].
+///
+/// It may be used to write generic code which accepts any of these types.
+pub trait ContainsInstance<T: Kind> {
+    /// Returns the associated [`Instance<T>`].
+    fn instance(&self) -> Instance<T>;
+}
+
+impl<T: Kind> ContainsInstance<T> for Instance<T> {
+    fn instance(&self) -> Instance<T> {
+        *self
+    }
+}
+
+/// A trait for any type which identifies an [`Entity`].
+///
+/// # Usage
+/// This trait is implemented for [`Entity`], [`Instance<T>`] and all instance wrappers, such as [`InstanceRef<T>`],
+/// [`InstanceMut<T>`], [`InstanceCommands<T>`] and [`InstanceWorldMut<T>`].
+///
+/// It may be used to write generic code which accepts either an entity or an instance of any kind.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// fn log(target: impl AsEntity) {
+///     println!("{}", target.entity());
+/// }
+///
+/// let mut world = World::new();
+/// let apple = world.spawn_instance(Apple).instance();
+/// log(apple);
+/// log(apple.entity());
+/// ```
+pub trait AsEntity {
+    /// Returns the associated [`Entity`].
+    fn entity(&self) -> Entity;
+}
+
+impl AsEntity for Entity {
+    fn entity(&self) -> Entity {
+        *self
+    }
+}
+
+impl<T: Kind> AsEntity for Instance<T> {
+    fn entity(&self) -> Entity {
+        self.0
+    }
+}
+
+/// Extension trait to convert anything which [contains an instance](ContainsInstance) into an [`Instance<Any>`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, Any};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Resource, Default)]
+/// struct Things(Vec<Instance<Any>>);
+///
+/// fn collect_apples(apples: Query<InstanceRef<Apple>>, mut things: ResMut<Things>) {
+///     for apple in apples.iter() {
+///         things.0.push(apple.any());
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(collect_apples);
+/// ```
+pub trait IntoInstanceAny<T: Kind> {
+    /// Returns the associated instance as an [`Instance<Any>`].
+    fn any(&self) -> Instance<Any>;
+}
+
+impl<T: Kind, I: ContainsInstance<T>> IntoInstanceAny<T> for I {
+    fn any(&self) -> Instance<Any> {
+        self.instance().cast_into_any()
+    }
+}
+
 /// A [`QueryData`] item which represents a reference to an [`Instance<T>`] and its associated [`Component`].
 ///
 /// # Usage
@@ -437,6 +1269,74 @@ impl<'a, T: Component> InstanceRef<'a, T> {
         })
     }
 
+    /// Creates a new [`InstanceRef<T>`] from an [`EntityWorldMut`] if it contains a given [`Component`] of type `T`.
+    ///
+    /// # Usage
+    /// This is useful to read the instance data while holding a mutable reference to its entity.
+    /// The returned [`InstanceRef<T>`] borrows the entity until it is dropped.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple {
+    ///     freshness: f32,
+    /// }
+    ///
+    /// #[derive(Component)]
+    /// struct Fresh;
+    ///
+    /// fn update_apple(mut entity: EntityWorldMut) {
+    ///     let fresh = InstanceRef::<Apple>::from_entity_mut(&entity)
+    ///         .is_some_and(|apple| apple.freshness >= 0.5);
+    ///     if fresh {
+    ///         entity.insert(Fresh);
+    ///     }
+    /// }
+    /// ```
+    pub fn from_entity_mut(entity: &'a EntityWorldMut) -> Option<Self> {
+        Self::from_entity(EntityRef::from(entity))
+    }
+
+    /// Creates a new [`InstanceRef<T>`] from an [`EntityRef`] if it contains a given [`Component`] of type `T`
+    /// and is also of [`Kind`] `K`.
+    ///
+    /// # Usage
+    /// This is useful for kinds which are defined by multiple components, where `T` is the primary component
+    /// of interest. See [`Instance::is_kind`] for details on how `K::Filter` is evaluated.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Ripe;
+    ///
+    /// struct RipeApple;
+    ///
+    /// impl Kind for RipeApple {
+    ///     type Filter = (With<Apple>, With<Ripe>);
+    /// }
+    ///
+    /// fn ripe_apples(world: &World) -> Vec<InstanceRef<Apple>> {
+    ///     world.iter_entities()
+    ///         .filter_map(|entity| InstanceRef::from_entity_for_kind::<RipeApple>(entity, world))
+    ///         .collect()
+    /// }
+    /// ```
+    pub fn from_entity_for_kind<K: Kind>(entity: EntityRef<'a>, world: &World) -> Option<Self> {
+        if !crate::is_kind::<K>(world, entity.id()) {
+            return None;
+        }
+        Self::from_entity(entity)
+    }
+
     /// Returns the associated [`Entity`].
     pub fn entity(&self) -> Entity {
         self.instance.entity()
@@ -446,9 +1346,70 @@ impl<'a, T: Component> InstanceRef<'a, T> {
     pub fn instance(&self) -> Instance<T> {
         self.instance
     }
-}
 
-impl<T: Component> Clone for InstanceRef<'_, T> {
+    /// Returns all children of this instance which are of [`Kind`] `C`, in order.
+    ///
+    /// See [`Instance::children_of_kind`] for more information.
+    pub fn children_of_kind<C: Kind>(&self, world: &World) -> Vec<Instance<C>> {
+        self.instance.children_of_kind(world)
+    }
+
+    /// Returns the [`InstanceCommands<T>`] of this instance from the given [`Commands`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple {
+    ///     freshness: f32,
+    /// }
+    ///
+    /// fn remove_rotten_apples(apples: Query<InstanceRef<Apple>>, mut commands: Commands) {
+    ///     for apple in apples.iter() {
+    ///         if apple.freshness <= 0.0 {
+    ///             apple.commands(&mut commands).despawn();
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(remove_rotten_apples);
+    /// ```
+    pub fn commands<'c>(&self, commands: &'c mut Commands) -> InstanceCommands<'c, T> {
+        commands.instance(self.instance)
+    }
+
+    /// Returns an owned [`InstanceData<T>`] with a clone of the associated [`Component`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::{prelude::*, InstanceData};
+    ///
+    /// #[derive(Component, Clone)]
+    /// struct Apple {
+    ///     freshness: f32,
+    /// }
+    ///
+    /// #[derive(Resource, Default)]
+    /// struct Snapshot(Vec<InstanceData<Apple>>);
+    ///
+    /// fn take_snapshot(apples: Query<InstanceRef<Apple>>, mut snapshot: ResMut<Snapshot>) {
+    ///     snapshot.0 = apples.iter().map(|apple| apple.to_owned_data()).collect();
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(take_snapshot);
+    /// ```
+    pub fn to_owned_data(&self) -> InstanceData<T>
+    where
+        T: Clone,
+    {
+        InstanceData::new(self.instance, self.data.clone())
+    }
+}
+
+impl<T: Component> Clone for InstanceRef<'_, T> {
     fn clone(&self) -> Self {
         *self
     }
@@ -456,6 +1417,18 @@ impl<T: Component> Clone for InstanceRef<'_, T> {
 
 impl<T: Component> Copy for InstanceRef<'_, T> {}
 
+impl<T: Component> ContainsInstance<T> for InstanceRef<'_, T> {
+    fn instance(&self) -> Instance<T> {
+        self.instance
+    }
+}
+
+impl<T: Component> AsEntity for InstanceRef<'_, T> {
+    fn entity(&self) -> Entity {
+        InstanceRef::entity(self)
+    }
+}
+
 impl<T: Component> From<InstanceRef<'_, T>> for Instance<T> {
     fn from(item: InstanceRef<T>) -> Self {
         item.instance()
@@ -496,9 +1469,115 @@ impl<T: Component> AsRef<T> for InstanceRef<'_, T> {
     }
 }
 
-impl<T: Component> fmt::Debug for InstanceRef<'_, T> {
+/// Formats this item as its [`Instance<T>`] followed by its associated [`Component`] (e.g. `Apple(12v1) Apple { .. }`).
+impl<T: Component + fmt::Debug> fmt::Debug for InstanceRef<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.instance())
+        fmt_instance_data(&self.instance, self.data, f)
+    }
+}
+
+/// Formats the given [`Instance<T>`] followed by its associated [`Component`].
+fn fmt_instance_data<T: Component + fmt::Debug>(
+    instance: &Instance<T>,
+    data: &T,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    fmt::Debug::fmt(instance, f)?;
+    f.write_str(" ")?;
+    fmt::Debug::fmt(data, f)
+}
+
+/// An owned snapshot of an [`Instance<T>`] and its associated [`Component`].
+///
+/// # Usage
+/// Unlike [`InstanceRef<T>`], this type does not borrow the component, so it may be stored beyond
+/// the lifetime of a query. This is useful for event payloads and deferred processing.
+///
+/// Note that the component data is not kept in sync with the world after this snapshot is taken.
+///
+/// See [`InstanceRef::to_owned_data`] for an example.
+#[derive(Clone)]
+pub struct InstanceData<T: Component> {
+    instance: Instance<T>,
+    data: T,
+}
+
+impl<T: Component> InstanceData<T> {
+    /// Creates a new [`InstanceData<T>`] from an [`Instance<T>`] and its component data.
+    pub fn new(instance: Instance<T>, data: T) -> Self {
+        Self { instance, data }
+    }
+
+    /// Returns the associated [`Entity`].
+    pub fn entity(&self) -> Entity {
+        self.instance.entity()
+    }
+
+    /// Returns the associated [`Instance<T>`].
+    pub fn instance(&self) -> Instance<T> {
+        self.instance
+    }
+
+    /// Consumes this snapshot and returns its component data.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T: Component> ContainsInstance<T> for InstanceData<T> {
+    fn instance(&self) -> Instance<T> {
+        self.instance
+    }
+}
+
+impl<T: Component> AsEntity for InstanceData<T> {
+    fn entity(&self) -> Entity {
+        InstanceData::entity(self)
+    }
+}
+
+impl<T: Component> From<InstanceData<T>> for Instance<T> {
+    fn from(item: InstanceData<T>) -> Self {
+        item.instance()
+    }
+}
+
+impl<T: Component> From<&InstanceData<T>> for Instance<T> {
+    fn from(item: &InstanceData<T>) -> Self {
+        item.instance()
+    }
+}
+
+impl<T: Component> Deref for InstanceData<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: Component> DerefMut for InstanceData<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+impl<T: Component> AsRef<Instance<T>> for InstanceData<T> {
+    fn as_ref(&self) -> &Instance<T> {
+        &self.instance
+    }
+}
+
+impl<T: Component> AsRef<T> for InstanceData<T> {
+    fn as_ref(&self) -> &T {
+        &self.data
+    }
+}
+
+/// Formats this snapshot as its [`Instance<T>`] followed by its associated [`Component`] (e.g. `Apple(12v1) Apple { .. }`).
+impl<T: Component + fmt::Debug> fmt::Debug for InstanceData<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt_instance_data(&self.instance, &self.data, f)
     }
 }
 
@@ -528,6 +1607,13 @@ impl<T: Component> InstanceMutReadOnlyItem<'_, T> {
     pub fn instance(&self) -> Instance<T> {
         self.instance
     }
+
+    /// Returns the [`InstanceCommands<T>`] of this instance from the given [`Commands`].
+    ///
+    /// See [`InstanceRef::commands`] for more information.
+    pub fn commands<'c>(&self, commands: &'c mut Commands) -> InstanceCommands<'c, T> {
+        commands.instance(self.instance)
+    }
 }
 
 impl<T: Component> From<InstanceMutReadOnlyItem<'_, T>> for Instance<T> {
@@ -550,6 +1636,18 @@ impl<T: Component> PartialEq for InstanceMutReadOnlyItem<'_, T> {
 
 impl<T: Component> Eq for InstanceMutReadOnlyItem<'_, T> {}
 
+impl<T: Component> ContainsInstance<T> for InstanceMutReadOnlyItem<'_, T> {
+    fn instance(&self) -> Instance<T> {
+        self.instance
+    }
+}
+
+impl<T: Component> AsEntity for InstanceMutReadOnlyItem<'_, T> {
+    fn entity(&self) -> Entity {
+        InstanceMutReadOnlyItem::entity(self)
+    }
+}
+
 impl<T: Component> Deref for InstanceMutReadOnlyItem<'_, T> {
     type Target = T;
 
@@ -558,9 +1656,10 @@ impl<T: Component> Deref for InstanceMutReadOnlyItem<'_, T> {
     }
 }
 
-impl<T: Component> fmt::Debug for InstanceMutReadOnlyItem<'_, T> {
+/// Formats this item as its [`Instance<T>`] followed by its associated [`Component`] (e.g. `Apple(12v1) Apple { .. }`).
+impl<T: Component + fmt::Debug> fmt::Debug for InstanceMutReadOnlyItem<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.instance())
+        fmt_instance_data(&self.instance, self.data, f)
     }
 }
 
@@ -584,6 +1683,90 @@ impl<'a, T: Component> InstanceMutItem<'a, T> {
     pub fn instance(&self) -> Instance<T> {
         self.instance
     }
+
+    /// Returns the [`InstanceCommands<T>`] of this instance from the given [`Commands`].
+    ///
+    /// See [`InstanceRef::commands`] for more information.
+    pub fn commands<'c>(&self, commands: &'c mut Commands) -> InstanceCommands<'c, T> {
+        commands.instance(self.instance)
+    }
+
+    /// Returns a read-only [`InstanceRef<T>`] which borrows from this item.
+    ///
+    /// The returned reference may not outlive this borrow, and accessing it does not flag the component as changed.
+    pub fn as_ref_instance(&self) -> InstanceRef<'_, T> {
+        InstanceRef {
+            instance: self.instance,
+            data: &self.data,
+        }
+    }
+
+    /// Returns a new [`InstanceMutItem<T>`] with a shorter lifetime which borrows from this item.
+    ///
+    /// # Usage
+    /// This is useful to pass this item into a function which consumes it, without giving up ownership.
+    /// Like [`Mut::reborrow`], any change made through the returned item flags the component as changed.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::{prelude::*, InstanceMutItem};
+    ///
+    /// #[derive(Component)]
+    /// struct Apple {
+    ///     freshness: f32,
+    /// }
+    ///
+    /// fn rot(mut apple: InstanceMutItem<Apple>) {
+    ///     apple.freshness -= 0.1;
+    /// }
+    ///
+    /// fn rot_apples_twice(mut apples: Query<InstanceMut<Apple>>) {
+    ///     for mut apple in apples.iter_mut() {
+    ///         rot(apple.reborrow());
+    ///         rot(apple);
+    ///     }
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(rot_apples_twice);
+    /// ```
+    pub fn reborrow(&mut self) -> InstanceMutItem<'_, T> {
+        InstanceMutItem {
+            instance: self.instance,
+            data: self.data.reborrow(),
+        }
+    }
+
+    /// Consumes this item and returns a [`Mut<U>`] into some part of its associated [`Component`].
+    ///
+    /// Like [`Mut::map_unchanged`], this does not flag the component as changed.
+    /// The returned [`Mut<U>`] shares the change ticks of the component, so any mutation through it does.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple {
+    ///     freshness: f32,
+    /// }
+    ///
+    /// fn rot(mut freshness: Mut<f32>) {
+    ///     *freshness -= 0.1;
+    /// }
+    ///
+    /// fn rot_apples(mut apples: Query<InstanceMut<Apple>>) {
+    ///     for apple in apples.iter_mut() {
+    ///         rot(apple.map_unchanged(|apple| &mut apple.freshness));
+    ///     }
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(rot_apples);
+    /// ```
+    pub fn map_unchanged<U: ?Sized>(self, f: impl FnOnce(&mut T) -> &mut U) -> Mut<'a, U> {
+        self.data.map_unchanged(f)
+    }
 }
 
 impl<T: Component> From<InstanceMutItem<'_, T>> for Instance<T> {
@@ -605,6 +1788,18 @@ impl<T: Component> PartialEq for InstanceMutItem<'_, T> {
 }
 impl<T: Component> Eq for InstanceMutItem<'_, T> {}
 
+impl<T: Component> ContainsInstance<T> for InstanceMutItem<'_, T> {
+    fn instance(&self) -> Instance<T> {
+        self.instance
+    }
+}
+
+impl<T: Component> AsEntity for InstanceMutItem<'_, T> {
+    fn entity(&self) -> Entity {
+        InstanceMutItem::entity(self)
+    }
+}
+
 impl<T: Component> Deref for InstanceMutItem<'_, T> {
     type Target = T;
 
@@ -637,124 +1832,1146 @@ impl<T: Component> AsMut<T> for InstanceMutItem<'_, T> {
     }
 }
 
-impl<T: Component> fmt::Debug for InstanceMutItem<'_, T> {
+/// Formats this item as its [`Instance<T>`] followed by its associated [`Component`] (e.g. `Apple(12v1) Apple { .. }`).
+impl<T: Component + fmt::Debug> fmt::Debug for InstanceMutItem<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self.instance())
-    }
-}
-
-/// Extension trait to access [`InstanceCommands<T>`] from [`Commands`].
-///
-/// See [`InstanceCommands`] for more information.
-pub trait GetInstanceCommands<T: Kind> {
-    /// Returns the [`InstanceCommands<T>`] for an [`Instance<T>`].
-    fn instance(&mut self, instance: Instance<T>) -> InstanceCommands<'_, T>;
-}
-
-impl<T: Kind> GetInstanceCommands<T> for Commands<'_, '_> {
-    fn instance(&mut self, instance: Instance<T>) -> InstanceCommands<'_, T> {
-        InstanceCommands(self.entity(instance.entity()), PhantomData)
+        fmt_instance_data(&self.instance, &*self.data, f)
     }
 }
 
-/// [`EntityCommands`] with kind semantics.
+/// A [`QueryData`] item which represents a mutable reference to an [`Instance<T>`] and several of its components.
 ///
 /// # Usage
-/// On its own, this type is not very useful. Instead, it is designed to be extended using traits.
-/// This allows you to design commands for a specific kind of an entity in a type-safe manner.
+/// This type is similar to [`InstanceMut<T>`], except it provides mutable access to multiple components at once.
+/// The first component of `P` is the primary component, which defines the kind of the instance.
+///
+/// For example, `InstancePartsMut<(A, B)>` is equivalent to `(Instance<A>, &mut A, &mut B)`.
+/// Each component is accessed as a [`Mut`], so change detection works independently for each part.
+///
+/// Note that all components in `P` must be distinct. Otherwise, the query has conflicting access and panics.
 ///
 /// # Example
 /// ```
 /// # use bevy::prelude::*;
-/// # use moonshine_kind::prelude::*;
+/// # use moonshine_kind::{prelude::*, InstancePartsMut};
 ///
 /// #[derive(Component)]
 /// struct Apple;
 ///
 /// #[derive(Component)]
-/// struct Eat;
+/// struct Freshness(f32);
 ///
-/// trait EatApple {
-///     fn eat(&mut self);
-/// }
+/// #[derive(Component)]
+/// struct Weight(f32);
 ///
-/// impl EatApple for InstanceCommands<'_, Apple> {
-///     fn eat(&mut self) {
-///         info!("Crunch!");
-///         self.despawn();
+/// fn rot(mut apples: Query<InstancePartsMut<(Apple, Freshness, Weight)>>) {
+///     for mut apple in apples.iter_mut() {
+///         let (_, freshness, weight) = apple.parts_mut();
+///         freshness.0 -= 0.1;
+///         weight.0 *= 0.99;
 ///     }
 /// }
 ///
-/// fn eat_apples(apples: Query<Instance<Apple>, With<Eat>>, mut commands: Commands) {
-///     for apple in apples.iter() {
-///         commands.instance(apple).eat();
-///     }
-/// }
+/// # bevy_ecs::system::assert_is_system(rot);
+/// ```
+#[derive(QueryData)]
+#[query_data(mutable)]
+pub struct InstancePartsMut<P: InstanceParts> {
+    instance: Instance<P::Kind>,
+    parts: P::Data,
+}
+
+/// A tuple of distinct components which may be accessed using [`InstancePartsMut`].
 ///
-/// # bevy_ecs::system::assert_is_system(eat_apples);
-pub struct InstanceCommands<'a, T: Kind>(EntityCommands<'a>, PhantomData<T>);
+/// The first component in the tuple defines the kind of the instance.
+pub trait InstanceParts: 'static {
+    /// The primary [`Component`] which defines the kind of the instance.
+    type Kind: Component;
 
-impl<'a, T: Kind> InstanceCommands<'a, T> {
-    /// Creates a new [`InstanceCommands<T>`] from [`EntityCommands`] without any validation.
-    ///
-    /// # Safety
-    /// Assumes `entity` is a valid instance of kind `T`.
-    pub unsafe fn from_entity_unchecked(entity: EntityCommands<'a>) -> Self {
-        Self(entity, PhantomData)
-    }
+    /// The [`QueryData`] used to access all parts mutably.
+    type Data: QueryData;
+}
 
-    /// Returns the associated [`Instance<T>`].
-    pub fn instance(&self) -> Instance<T> {
-        // SAFE: `self.entity()` must be a valid instance of kind `T`.
-        unsafe { Instance::from_entity_unchecked(self.entity()) }
-    }
+macro_rules! impl_instance_parts {
+    ($($t:ident),+) => {
+        impl<K: Component, $($t: Component),+> InstanceParts for (K, $($t,)+) {
+            type Kind = K;
+            type Data = (&'static mut K, $(&'static mut $t,)+);
+        }
+    };
+}
 
+impl_instance_parts!(A);
+impl_instance_parts!(A, B);
+impl_instance_parts!(A, B, C);
+impl_instance_parts!(A, B, C, D);
+
+impl<'w, P: InstanceParts> InstancePartsMutItem<'w, P> {
     /// Returns the associated [`Entity`].
     pub fn entity(&self) -> Entity {
-        self.0.id()
-    }
-
-    /// Returns the associated [`EntityCommands`].
-    pub fn as_entity(&mut self) -> &mut EntityCommands<'a> {
-        &mut self.0
-    }
-
-    pub fn insert(&mut self, bundle: impl Bundle) -> &mut Self {
-        self.0.insert(bundle);
-        self
+        self.instance.entity()
     }
 
-    pub fn remove<U: Component>(&mut self) -> &mut Self {
-        self.0.remove::<U>();
-        self
+    /// Returns the associated [`Instance`] of the primary component.
+    pub fn instance(&self) -> Instance<P::Kind> {
+        self.instance
     }
 
-    pub fn reborrow(&mut self) -> InstanceCommands<'_, T> {
-        InstanceCommands(self.0.reborrow(), PhantomData)
+    /// Returns all components of this instance.
+    pub fn parts_mut(&mut self) -> &mut <P::Data as WorldQuery>::Item<'w> {
+        &mut self.parts
     }
 
-    pub fn cast_into<U: Kind>(self) -> InstanceCommands<'a, U>
-    where
-        T: CastInto<U>,
-    {
-        // SAFE: `CastInto<U>` is implemented for `T`.
-        unsafe { InstanceCommands::from_entity_unchecked(self.0) }
+    /// Consumes this item and returns all components of this instance.
+    pub fn into_parts(self) -> <P::Data as WorldQuery>::Item<'w> {
+        self.parts
     }
 }
 
-impl<'a, T: Kind> From<InstanceCommands<'a, T>> for Instance<T> {
-    fn from(commands: InstanceCommands<'a, T>) -> Self {
-        commands.instance()
+impl<P: InstanceParts> ContainsInstance<P::Kind> for InstancePartsMutItem<'_, P> {
+    fn instance(&self) -> Instance<P::Kind> {
+        self.instance
     }
 }
 
-impl<'a, T: Kind> From<&InstanceCommands<'a, T>> for Instance<T> {
-    fn from(commands: &InstanceCommands<'a, T>) -> Self {
-        commands.instance()
+impl<P: InstanceParts> AsEntity for InstancePartsMutItem<'_, P> {
+    fn entity(&self) -> Entity {
+        InstancePartsMutItem::entity(self)
     }
 }
 
-impl<'a, T: Kind> Deref for InstanceCommands<'a, T> {
+/// Extension trait to access [`InstanceRef<T>`] from a [`World`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Eat(Instance<Apple>, Instance<Apple>);
+///
+/// fn eat_apples(world: &World, eat: &Eat) {
+///     let [a, b] = world.instance_many([eat.0, eat.1]);
+///     if let (Some(a), Some(b)) = (a, b) {
+///         println!("{:?} and {:?} are both still here!", a.instance(), b.instance());
+///     }
+/// }
+/// ```
+pub trait ComponentInstance {
+    /// Returns an [`InstanceRef<T>`] for the given [`Entity`] if it contains a [`Component`] of type `T`.
+    fn get_instance<T: Component>(&self, entity: Entity) -> Option<InstanceRef<'_, T>>;
+
+    /// Returns an [`InstanceRef<T>`] for the given [`Instance<T>`].
+    ///
+    /// # Panics
+    /// If the instance is not valid in this world.
+    fn instance<T: Component>(&self, instance: Instance<T>) -> InstanceRef<'_, T> {
+        self.get_instance(instance.entity())
+            .unwrap_or_else(|| panic!("{instance:?} is not a valid instance"))
+    }
+
+    /// Returns an [`InstanceRef<T>`] for each of the given instances, or `None` if the instance is not valid.
+    ///
+    /// Since the returned items are read-only, the same instance may be given more than once.
+    fn instance_many<T: Component, const N: usize>(
+        &self,
+        instances: [Instance<T>; N],
+    ) -> [Option<InstanceRef<'_, T>>; N] {
+        instances.map(|instance| self.get_instance(instance.entity()))
+    }
+
+    /// Same as [`ComponentInstance::instance_many`], but for a slice of instances.
+    fn instance_many_slice<T: Component>(
+        &self,
+        instances: &[Instance<T>],
+    ) -> Vec<Option<InstanceRef<'_, T>>> {
+        instances
+            .iter()
+            .map(|instance| self.get_instance(instance.entity()))
+            .collect()
+    }
+
+    /// Returns an [`Instance<T>`] for the given [`Entity`] if it matches `T::Filter`.
+    ///
+    /// Unlike [`ComponentInstance::get_instance`], this works for any [`Kind`], not just components.
+    ///
+    /// See [`Instance::is_kind`] for details on how the filter is evaluated.
+    fn get_instance_kind<T: Kind>(&self, entity: Entity) -> Option<Instance<T>>;
+}
+
+impl ComponentInstance for World {
+    fn get_instance<T: Component>(&self, entity: Entity) -> Option<InstanceRef<'_, T>> {
+        self.get_entity(entity)
+            .ok()
+            .and_then(InstanceRef::from_entity)
+    }
+
+    fn get_instance_kind<T: Kind>(&self, entity: Entity) -> Option<Instance<T>> {
+        // SAFE: Kind is validated by `is_kind` before construction.
+        crate::is_kind::<T>(self, entity)
+            .then(|| unsafe { Instance::from_entity_unchecked(entity) })
+    }
+}
+
+impl ComponentInstance for DeferredWorld<'_> {
+    fn get_instance<T: Component>(&self, entity: Entity) -> Option<InstanceRef<'_, T>> {
+        World::get_instance(self, entity)
+    }
+
+    fn get_instance_kind<T: Kind>(&self, entity: Entity) -> Option<Instance<T>> {
+        World::get_instance_kind(self, entity)
+    }
+}
+
+/// Extension trait to access instances from within [component hooks](bevy_ecs::component::ComponentHooks).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy::ecs::{component::ComponentId, world::DeferredWorld};
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// #[component(on_add = on_add_apple)]
+/// struct Apple;
+///
+/// fn on_add_apple(world: DeferredWorld, entity: Entity, _: ComponentId) {
+///     let apple = world.instance_in_hook::<Apple>(entity);
+///     println!("{apple:?} was added!");
+/// }
+///
+/// let mut world = World::new();
+/// world.spawn(Apple);
+/// ```
+pub trait HookInstance {
+    /// Returns the [`Instance<T>`] of the given [`Entity`] from within a hook of [`Component`] `T`.
+    ///
+    /// # Usage
+    /// During `on_add`, `on_insert`, `on_replace` and `on_remove` hooks of `T`, the entity is guaranteed
+    /// to contain `T`, so no validation is needed.
+    ///
+    /// In debug builds, this function panics if the entity does not contain `T`.
+    fn instance_in_hook<T: Component>(&self, entity: Entity) -> Instance<T>;
+
+    /// Returns the [`Instance<T>`] of the given [`Entity`] if it matches `T::Filter`.
+    ///
+    /// This is useful from within a hook of a component which is part of some other [`Kind`].
+    ///
+    /// See [`ComponentInstance::get_instance_kind`] for more information.
+    fn instance_in_hook_kind<T: Kind>(&self, entity: Entity) -> Option<Instance<T>>;
+}
+
+impl HookInstance for DeferredWorld<'_> {
+    fn instance_in_hook<T: Component>(&self, entity: Entity) -> Instance<T> {
+        debug_assert!(
+            self.get_entity(entity)
+                .is_ok_and(|entity| entity.contains::<T>()),
+            "{entity} does not have component {}",
+            T::debug_name()
+        );
+        // SAFE: Entity must contain `T` during any hook of `T`.
+        unsafe { Instance::from_entity_unchecked(entity) }
+    }
+
+    fn instance_in_hook_kind<T: Kind>(&self, entity: Entity) -> Option<Instance<T>> {
+        self.get_instance_kind(entity)
+    }
+}
+
+/// Extension trait to mutably access instances from a [`World`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple {
+///     freshness: f32,
+/// }
+///
+/// #[derive(Component)]
+/// struct Rotten;
+///
+/// struct FreshApple;
+///
+/// impl Kind for FreshApple {
+///     type Filter = (With<Apple>, Without<Rotten>);
+/// }
+///
+/// let mut world = World::new();
+/// let fresh = world.spawn(Apple { freshness: 1.0 }).id();
+/// let rotten = world.spawn((Apple { freshness: 0.0 }, Rotten)).id();
+///
+/// let mut apple = world.get_instance_mut_kind::<FreshApple>(fresh).unwrap();
+/// apple.get_mut::<Apple>().unwrap().freshness = 0.5;
+/// assert!(world.get_instance_mut_kind::<FreshApple>(rotten).is_none());
+/// assert_eq!(world.get_instance_mut::<Apple>(fresh).unwrap().freshness, 0.5);
+/// ```
+pub trait ComponentInstanceMut {
+    /// Returns an [`InstanceMutItem<T>`] for the given [`Entity`] if it contains a [`Component`] of type `T`.
+    fn get_instance_mut<T: Component>(&mut self, entity: Entity) -> Option<InstanceMutItem<'_, T>>;
+
+    /// Returns an [`InstanceWorldMut<T>`] for the given [`Entity`] if it matches `T::Filter`.
+    ///
+    /// # Usage
+    /// Unlike [`ComponentInstanceMut::get_instance_mut`], this function evaluates the full filter of `T`,
+    /// rather than just checking for the presence of a single component. This makes it suitable for any [`Kind`],
+    /// including kinds which are backed by a component but require more than just that component.
+    fn get_instance_mut_kind<T: Kind>(&mut self, entity: Entity)
+        -> Option<InstanceWorldMut<'_, T>>;
+
+    /// Returns the [`InstanceWorldMut<T>`] of the given [`Instance<T>`] if it is still of kind `T`.
+    ///
+    /// # Usage
+    /// This is the structural counterpart of [`ComponentInstanceMut::get_instance_mut`].
+    /// It allows inserting bundles, removing components, or adding children to an instance, rather than
+    /// only accessing its component.
+    ///
+    /// Returns `None` if the instance no longer exists, or is no longer of kind `T`.
+    fn instance_world_mut<T: Kind>(
+        &mut self,
+        instance: Instance<T>,
+    ) -> Option<InstanceWorldMut<'_, T>> {
+        self.get_instance_mut_kind(instance.entity())
+    }
+}
+
+impl ComponentInstanceMut for World {
+    fn get_instance_mut<T: Component>(&mut self, entity: Entity) -> Option<InstanceMutItem<'_, T>> {
+        InstanceMutItem::from_entity(self, entity)
+    }
+
+    fn get_instance_mut_kind<T: Kind>(
+        &mut self,
+        entity: Entity,
+    ) -> Option<InstanceWorldMut<'_, T>> {
+        crate::register_kind_components::<T>(self);
+        if !crate::is_kind::<T>(self, entity) {
+            return None;
+        }
+        // SAFE: Kind is validated by `is_kind` above.
+        Some(unsafe { InstanceWorldMut::from_entity_unchecked(self.entity_mut(entity)) })
+    }
+}
+
+/// Extension trait to access an [`EntityWorldMut`] as an [`InstanceRef<T>`] or [`InstanceMut<T>`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple {
+///     freshness: f32,
+/// }
+///
+/// let mut world = World::new();
+/// let mut entity = world.spawn(Apple { freshness: 1.0 });
+/// entity.as_instance_mut::<Apple>().unwrap().freshness = 0.5;
+/// assert_eq!(entity.as_instance_ref::<Apple>().unwrap().freshness, 0.5);
+/// ```
+pub trait AsInstance {
+    /// Returns this entity as an [`InstanceRef<T>`] if it contains a [`Component`] of type `T`.
+    fn as_instance_ref<T: Component>(&self) -> Option<InstanceRef<'_, T>>;
+
+    /// Returns this entity as an [`InstanceMutItem<T>`] if it contains a [`Component`] of type `T`.
+    fn as_instance_mut<T: Component>(&mut self) -> Option<InstanceMutItem<'_, T>>;
+}
+
+impl AsInstance for EntityWorldMut<'_> {
+    fn as_instance_ref<T: Component>(&self) -> Option<InstanceRef<'_, T>> {
+        InstanceRef::from_entity_mut(self)
+    }
+
+    fn as_instance_mut<T: Component>(&mut self) -> Option<InstanceMutItem<'_, T>> {
+        // SAFE: Kind is validated by `get_mut` below.
+        let instance = unsafe { Instance::from_entity_unchecked(self.id()) };
+        self.get_mut::<T>()
+            .map(|data| InstanceMutItem { instance, data })
+    }
+}
+
+/// Extension trait to get an [`Instance<T>`] from a [`Query`] by [`Entity`].
+///
+/// # Usage
+/// This is similar to [`Query::get`], except it returns an [`Option`] rather than a [`Result`],
+/// consistent with [`ComponentInstance::get_instance`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Eat(Entity);
+///
+/// fn eat_apples(eat: Query<&Eat>, apples: Query<Instance<Apple>>) {
+///     for Eat(entity) in eat.iter() {
+///         if let Some(apple) = apples.get_instance(*entity) {
+///             println!("{apple:?} was eaten!");
+///         }
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(eat_apples);
+/// ```
+pub trait GetInstance<T: Kind> {
+    /// Returns the [`Instance<T>`] of the given [`Entity`] if it matches this query.
+    fn get_instance(&self, entity: Entity) -> Option<Instance<T>>;
+}
+
+impl<T: Kind, F: QueryFilter> GetInstance<T> for Query<'_, '_, Instance<T>, F> {
+    fn get_instance(&self, entity: Entity) -> Option<Instance<T>> {
+        self.get(entity).ok()
+    }
+}
+
+impl<T: Component, F: QueryFilter> GetInstance<T> for Query<'_, '_, InstanceRef<'_, T>, F> {
+    fn get_instance(&self, entity: Entity) -> Option<Instance<T>> {
+        self.get(entity).ok().map(|item| item.instance())
+    }
+}
+
+impl<T: Component, F: QueryFilter> GetInstance<T> for Query<'_, '_, InstanceMut<T>, F> {
+    fn get_instance(&self, entity: Entity) -> Option<Instance<T>> {
+        self.get(entity).ok().map(|item| item.instance())
+    }
+}
+
+/// Extension trait to get the single [`Instance<T>`] of a [`Query`].
+///
+/// # Usage
+/// This is similar to [`Query::get_single`], except its error includes the [`Kind::debug_name`] of `T`,
+/// which makes it clear which kind assumption was broken.
+///
+/// [`Single<Instance<T>>`](Single) may still be used when skipping the system is preferred.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Human;
+///
+/// fn greet_human(humans: Query<Instance<Human>>) {
+///     match humans.get_single_instance() {
+///         Ok(human) => println!("Hello, {human:?}!"),
+///         Err(error) => println!("{error}"),
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(greet_human);
+/// ```
+pub trait GetSingleInstance<T: Kind> {
+    /// Returns the single [`Instance<T>`] of this query, or a [`KindSingleError`] if there is not exactly one.
+    fn get_single_instance(&self) -> Result<Instance<T>, KindSingleError>;
+}
+
+impl<T: Kind, F: QueryFilter> GetSingleInstance<T> for Query<'_, '_, Instance<T>, F> {
+    fn get_single_instance(&self) -> Result<Instance<T>, KindSingleError> {
+        let mut instances = self.iter();
+        let Some(instance) = instances.next() else {
+            return Err(KindSingleError::NoInstances {
+                kind: T::debug_name(),
+            });
+        };
+        if instances.next().is_some() {
+            return Err(KindSingleError::MultipleInstances {
+                kind: T::debug_name(),
+                count: 2 + instances.count(),
+            });
+        }
+        Ok(instance)
+    }
+}
+
+/// An error returned by [`GetSingleInstance::get_single_instance`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KindSingleError {
+    /// The query matched no instances.
+    NoInstances {
+        /// The [`Kind::debug_name`] of the expected kind.
+        kind: String,
+    },
+    /// The query matched more than one instance.
+    MultipleInstances {
+        /// The [`Kind::debug_name`] of the expected kind.
+        kind: String,
+        /// The number of matched instances.
+        count: usize,
+    },
+}
+
+/// Formats this error using the name of its kind (e.g. `expected exactly one Fruit, found 3`).
+impl fmt::Display for KindSingleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoInstances { kind } => write!(f, "expected exactly one {kind}, found none"),
+            Self::MultipleInstances { kind, count } => {
+                write!(f, "expected exactly one {kind}, found {count}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for KindSingleError {}
+
+/// Extension trait to get the target of a component lifecycle [`Trigger`] as an [`Instance<T>`].
+///
+/// # Usage
+/// Observers of [`OnAdd`], [`OnInsert`], [`OnReplace`] and [`OnRemove`] for some [`Component`] `T`
+/// are always triggered while the target entity contains `T`.
+/// This allows the target to be safely accessed as an [`Instance<T>`] without any validation.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// fn on_apple_added(trigger: Trigger<OnAdd, Apple>) {
+///     let apple: Instance<Apple> = trigger.target_instance();
+///     println!("{apple:?} was added!");
+/// }
+///
+/// let mut world = World::new();
+/// world.add_observer(on_apple_added);
+/// world.spawn(Apple);
+/// ```
+pub trait GetTriggerTargetInstance<T: Kind> {
+    /// Returns the target of this trigger as an [`Instance<T>`].
+    fn target_instance(&self) -> Instance<T>;
+}
+
+impl<T: Component> GetTriggerTargetInstance<T> for Trigger<'_, OnAdd, T> {
+    fn target_instance(&self) -> Instance<T> {
+        // SAFE: Target of `OnAdd` for `T` must contain `T`.
+        unsafe { Instance::from_entity_unchecked(self.entity()) }
+    }
+}
+
+impl<T: Component> GetTriggerTargetInstance<T> for Trigger<'_, OnInsert, T> {
+    fn target_instance(&self) -> Instance<T> {
+        // SAFE: Target of `OnInsert` for `T` must contain `T`.
+        unsafe { Instance::from_entity_unchecked(self.entity()) }
+    }
+}
+
+impl<T: Component> GetTriggerTargetInstance<T> for Trigger<'_, OnReplace, T> {
+    fn target_instance(&self) -> Instance<T> {
+        // SAFE: `OnReplace` for `T` is triggered before `T` is replaced.
+        unsafe { Instance::from_entity_unchecked(self.entity()) }
+    }
+}
+
+impl<T: Component> GetTriggerTargetInstance<T> for Trigger<'_, OnRemove, T> {
+    fn target_instance(&self) -> Instance<T> {
+        // SAFE: `OnRemove` for `T` is triggered before `T` is removed.
+        unsafe { Instance::from_entity_unchecked(self.entity()) }
+    }
+}
+
+/// Extension trait to access [`InstanceCommands<T>`] from [`Commands`].
+///
+/// See [`InstanceCommands`] for more information.
+pub trait GetInstanceCommands<T: Kind> {
+    /// Returns the [`InstanceCommands<T>`] for an [`Instance<T>`].
+    fn instance(&mut self, instance: Instance<T>) -> InstanceCommands<'_, T>;
+}
+
+impl<T: Kind> GetInstanceCommands<T> for Commands<'_, '_> {
+    fn instance(&mut self, instance: Instance<T>) -> InstanceCommands<'_, T> {
+        InstanceCommands(self.entity(instance.entity()), PhantomData)
+    }
+}
+
+/// Extension trait to access [`InstanceCommands<T>`] from [`Commands`] using only an [`Entity`].
+///
+/// # Usage
+/// This is useful when an [`Instance<T>`] is not available, but the entity is known to be of kind `T`,
+/// such as an entity stored in a component by other code. Unlike [`GetInstanceCommands::instance`],
+/// this does not require an [`Instance<T>`] and avoids borrowing the [`World`] to validate the entity.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Ripe;
+///
+/// #[derive(Resource)]
+/// struct Target(Entity);
+///
+/// fn ripen(target: Res<Target>, mut commands: Commands) {
+///     commands.get_instance_commands::<Apple>(target.0).insert(Ripe);
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(ripen);
+/// ```
+pub trait GetEntityInstanceCommands {
+    /// Returns the [`InstanceCommands<T>`] for the given [`Entity`].
+    ///
+    /// The kind of the entity is validated when the queued check is applied.
+    /// The check panics if the entity is not of kind `T` at that point.
+    /// If the entity is despawned before then, the check is skipped.
+    fn get_instance_commands<T: Kind>(&mut self, entity: Entity) -> InstanceCommands<'_, T>;
+
+    /// Returns the [`InstanceCommands<T>`] for the given [`Entity`] without any validation.
+    ///
+    /// # Safety
+    /// Assumes `entity` is a valid instance of kind `T`.
+    unsafe fn get_instance_commands_unchecked<T: Kind>(
+        &mut self,
+        entity: Entity,
+    ) -> InstanceCommands<'_, T>;
+}
+
+impl GetEntityInstanceCommands for Commands<'_, '_> {
+    fn get_instance_commands<T: Kind>(&mut self, entity: Entity) -> InstanceCommands<'_, T> {
+        self.entity(entity).into_instance()
+    }
+
+    unsafe fn get_instance_commands_unchecked<T: Kind>(
+        &mut self,
+        entity: Entity,
+    ) -> InstanceCommands<'_, T> {
+        self.entity(entity).into_instance_unchecked()
+    }
+}
+
+/// Extension trait to convert [`EntityCommands`] into [`InstanceCommands<T>`].
+///
+/// # Usage
+/// [`SpawnInstance::spawn_instance`] requires a [`KindBundle`]. When an entity is spawned with any other
+/// bundle (such as a tuple of components) which is known to be of some kind `T`, these functions may be
+/// used to convert its [`EntityCommands`] into [`InstanceCommands<T>`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Ripe;
+///
+/// fn spawn_ripe_apple(mut commands: Commands) -> Instance<Apple> {
+///     commands.spawn((Apple, Ripe)).into_instance::<Apple>().instance()
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(spawn_ripe_apple);
+/// ```
+pub trait IntoInstanceCommands<'a> {
+    /// Converts these [`EntityCommands`] into [`InstanceCommands<T>`] and queues a validation of its kind.
+    ///
+    /// The kind of the entity is validated when the queued check is applied.
+    /// The check panics if the entity is not of kind `T` at that point.
+    /// If the entity is despawned before then, the check is skipped.
+    fn into_instance<T: Kind>(self) -> InstanceCommands<'a, T>;
+
+    /// Converts these [`EntityCommands`] into [`InstanceCommands<T>`] without any validation.
+    ///
+    /// # Safety
+    /// Assumes the entity is a valid instance of kind `T` once all commands queued before this call are applied.
+    /// Typically, this means the entity was just spawned with a bundle which guarantees it matches `T::Filter`.
+    unsafe fn into_instance_unchecked<T: Kind>(self) -> InstanceCommands<'a, T>;
+}
+
+impl<'a> IntoInstanceCommands<'a> for EntityCommands<'a> {
+    fn into_instance<T: Kind>(mut self) -> InstanceCommands<'a, T> {
+        self.queue(|entity: Entity, world: &mut World| {
+            if world.get_entity(entity).is_ok() {
+                crate::register_kind_components::<T>(world);
+                assert!(
+                    crate::is_kind::<T>(world, entity),
+                    "{entity} is not of kind {}",
+                    T::debug_name()
+                );
+            }
+        });
+        // SAFE: Kind of the entity is validated when the queued command is applied.
+        unsafe { InstanceCommands::from_entity_unchecked(self) }
+    }
+
+    unsafe fn into_instance_unchecked<T: Kind>(self) -> InstanceCommands<'a, T> {
+        InstanceCommands::from_entity_unchecked(self)
+    }
+}
+
+/// [`EntityCommands`] with kind semantics.
+///
+/// # Usage
+/// On its own, this type is not very useful. Instead, it is designed to be extended using traits.
+/// This allows you to design commands for a specific kind of an entity in a type-safe manner.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Eat;
+///
+/// trait EatApple {
+///     fn eat(&mut self);
+/// }
+///
+/// impl EatApple for InstanceCommands<'_, Apple> {
+///     fn eat(&mut self) {
+///         info!("Crunch!");
+///         self.despawn();
+///     }
+/// }
+///
+/// fn eat_apples(apples: Query<Instance<Apple>, With<Eat>>, mut commands: Commands) {
+///     for apple in apples.iter() {
+///         commands.instance(apple).eat();
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(eat_apples);
+pub struct InstanceCommands<'a, T: Kind>(EntityCommands<'a>, PhantomData<T>);
+
+impl<'a, T: Kind> InstanceCommands<'a, T> {
+    /// Creates a new [`InstanceCommands<T>`] from [`EntityCommands`] without any validation.
+    ///
+    /// # Safety
+    /// Assumes `entity` is a valid instance of kind `T`.
+    pub unsafe fn from_entity_unchecked(entity: EntityCommands<'a>) -> Self {
+        Self(entity, PhantomData)
+    }
+
+    /// Returns the associated [`Instance<T>`].
+    pub fn instance(&self) -> Instance<T> {
+        self.to_instance()
+    }
+
+    /// Returns the associated [`Instance<T>`].
+    ///
+    /// # Usage
+    /// This is the canonical way to get the [`Instance<T>`] of these commands.
+    /// Unlike [`instance`](Self::instance), its name does not overlap with [`ContainsInstance::instance`],
+    /// so it is never ambiguous which method is called.
+    pub fn to_instance(&self) -> Instance<T> {
+        // SAFE: `self.0.id()` must be a valid instance of kind `T`.
+        unsafe { Instance::from_entity_unchecked(self.0.id()) }
+    }
+
+    /// Returns the associated [`Entity`].
+    pub fn entity(&self) -> Entity {
+        self.0.id()
+    }
+
+    /// Returns the associated [`EntityCommands`].
+    pub fn as_entity(&mut self) -> &mut EntityCommands<'a> {
+        &mut self.0
+    }
+
+    pub fn insert(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.0.insert(bundle);
+        self
+    }
+
+    pub fn remove<U: Component>(&mut self) -> &mut Self {
+        self.0.remove::<U>();
+        self
+    }
+
+    /// Same as [`insert`](Self::insert), but does nothing if this instance is despawned before the command is applied.
+    ///
+    /// See [`EntityCommands::try_insert`] for more details.
+    pub fn try_insert(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.0.try_insert(bundle);
+        self
+    }
+
+    /// Removes all components from this instance except those in the given [`Bundle`].
+    ///
+    /// See [`EntityCommands::retain`] for more details.
+    ///
+    /// # Usage
+    /// This instance is only guaranteed to remain of kind `T` if all components required by `T`
+    /// are part of `B`. Otherwise, use [`insert_instance`](Self::insert_instance) to re-acquire a valid kind.
+    pub fn retain<B: Bundle>(&mut self) -> &mut Self {
+        self.0.retain::<B>();
+        self
+    }
+
+    /// Logs the names of all components of this instance at the `info` level.
+    ///
+    /// # Usage
+    /// This is similar to [`EntityCommands::log_components`], except the output is prefixed with this
+    /// instance formatted using [`Kind::debug_name`] (e.g. `Apple(12v1) has components: [..]`).
+    ///
+    /// This is useful to debug instances which do not match their own kind filter.
+    /// If this instance is despawned before the command is applied, nothing is logged.
+    pub fn log_components(&mut self) -> &mut Self {
+        self.0.queue(|entity: Entity, world: &mut World| {
+            if let Some(message) = components_message::<T>(world, entity) {
+                bevy_utils::tracing::info!("{message}");
+            }
+        });
+        self
+    }
+
+    /// Inserts a [`KindBundle`] into this instance and returns its [`InstanceCommands<U>`].
+    ///
+    /// # Usage
+    /// This is useful when inserting a component which refines the kind of this instance.
+    ///
+    /// The returned commands reborrow these commands, so they must be dropped before these
+    /// commands can be used again. Both refer to the same entity.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Fruit;
+    ///
+    /// #[derive(Component)]
+    /// struct Ripe;
+    ///
+    /// fn ripen(fruit: Instance<Fruit>, mut commands: Commands) -> Instance<Ripe> {
+    ///     commands.instance(fruit).insert_instance(Ripe).instance()
+    /// }
+    /// ```
+    pub fn insert_instance<U: KindBundle>(&mut self, bundle: U) -> InstanceCommands<'_, U::Kind> {
+        self.0.insert(bundle);
+        // SAFE: `U::Kind` is inserted before any other commands are queued for this entity.
+        unsafe { InstanceCommands::from_entity_unchecked(self.0.reborrow()) }
+    }
+
+    /// Adds an [`Observer`] which watches this instance for [`Event`] `E`.
+    ///
+    /// # Usage
+    /// This is similar to [`EntityCommands::observe`], except it keeps the kind of this instance,
+    /// which allows further commands to be chained in a kind-safe manner.
+    ///
+    /// Since the observer only watches this instance, the target of any [`Trigger<E>`] it receives
+    /// is always of kind `T`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Event)]
+    /// struct Gobble;
+    ///
+    /// fn spawn_apple(mut commands: Commands) -> Instance<Apple> {
+    ///     commands
+    ///         .spawn_instance(Apple)
+    ///         .observe_instance(|trigger: Trigger<Gobble>| {
+    ///             println!("{:?} was gobbled!", trigger.entity());
+    ///         })
+    ///         .instance()
+    /// }
+    /// ```
+    pub fn observe_instance<E: Event, B: Bundle, M>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M>,
+    ) -> &mut Self {
+        self.0.observe(observer);
+        self
+    }
+
+    /// Spawns a new [`Instance<C>`] as a child of this instance and returns its [`InstanceCommands<C>`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Tree;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// fn spawn_tree(mut commands: Commands) {
+    ///     let apple: Instance<Apple> = commands
+    ///         .spawn_instance(Tree)
+    ///         .spawn_child_instance(Apple)
+    ///         .instance();
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(spawn_tree);
+    /// ```
+    pub fn spawn_child_instance<C: KindBundle>(
+        &mut self,
+        child: C,
+    ) -> InstanceCommands<'_, C::Kind> {
+        let parent = self.entity();
+        let mut child = self.0.commands_mut().spawn_instance(child);
+        child.set_parent(parent);
+        child
+    }
+
+    /// Spawns a new [`Instance<C>`] as a child of this instance for each bundle in the given iterator,
+    /// and returns all spawned instances in order.
+    ///
+    /// # Usage
+    /// The returned instances are reserved immediately, but their bundles are inserted and parented
+    /// only when the queued commands are applied. Any commands queued on them afterwards are applied
+    /// after they are spawned and parented.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Basket;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple(u32);
+    ///
+    /// fn spawn_basket(mut commands: Commands) -> Vec<Instance<Apple>> {
+    ///     commands
+    ///         .spawn_instance(Basket)
+    ///         .spawn_child_instances((0..3).map(Apple))
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(spawn_basket);
+    /// ```
+    pub fn spawn_child_instances<C: KindBundle>(
+        &mut self,
+        children: impl IntoIterator<Item = C>,
+    ) -> Vec<Instance<C::Kind>> {
+        children
+            .into_iter()
+            .map(|child| self.spawn_child_instance(child).instance())
+            .collect()
+    }
+
+    /// Spawns a new [`Instance<C>`] as a child of this instance and passes its [`InstanceCommands<C>`] to the given function.
+    ///
+    /// # Usage
+    /// This is similar to [`spawn_child_instance`](Self::spawn_child_instance), but returns this instance
+    /// to allow building a kind-safe hierarchy in a single expression.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Tree;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Seed;
+    ///
+    /// fn spawn_tree(mut commands: Commands) -> Instance<Tree> {
+    ///     commands
+    ///         .spawn_instance(Tree)
+    ///         .with_child_instance(Apple, |mut apple: InstanceCommands<Apple>| {
+    ///             apple.with_child_instance(Seed, |seed: InstanceCommands<Seed>| {
+    ///                 println!("Spawned {:?}", seed.instance());
+    ///             });
+    ///         })
+    ///         .with_child_instance(Apple, |apple: InstanceCommands<Apple>| {
+    ///             println!("Spawned {:?}", apple.instance());
+    ///         })
+    ///         .instance()
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(spawn_tree);
+    /// ```
+    pub fn with_child_instance<C: KindBundle>(
+        &mut self,
+        child: C,
+        f: impl FnOnce(InstanceCommands<'_, C::Kind>),
+    ) -> &mut Self {
+        f(self.spawn_child_instance(child));
+        self
+    }
+
+    pub fn reborrow(&mut self) -> InstanceCommands<'_, T> {
+        InstanceCommands(self.0.reborrow(), PhantomData)
+    }
+
+    /// Converts this instance into an instance of another kind [`Kind`] `U`.
+    ///
+    /// In debug builds, a command is queued to validate that the entity is of kind `U` when it is applied.
+    pub fn cast_into<U: Kind>(mut self) -> InstanceCommands<'a, U>
+    where
+        T: CastInto<U>,
+    {
+        if cfg!(debug_assertions) {
+            self.0.queue(|entity: Entity, world: &mut World| {
+                assert!(
+                    !crate::is_kind_mismatch::<U>(world, entity),
+                    "{entity} is not of kind {}",
+                    U::debug_name()
+                );
+            });
+        }
+        // SAFE: `CastInto<U>` is implemented for `T`.
+        unsafe { InstanceCommands::from_entity_unchecked(self.0) }
+    }
+}
+
+impl<T: Component> InstanceCommands<'_, T> {
+    /// Despawns this instance if the given predicate returns `true` for its associated [`Component`].
+    ///
+    /// # Usage
+    /// The predicate is evaluated when this command is applied, not when it is queued.
+    /// If the entity is despawned, or the component is removed before then, this command does nothing.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple {
+    ///     freshness: f32,
+    /// }
+    ///
+    /// fn remove_rotten_apples(apples: Query<Instance<Apple>>, mut commands: Commands) {
+    ///     for apple in apples.iter() {
+    ///         commands.instance(apple).despawn_if(|apple| apple.freshness <= 0.0);
+    ///     }
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(remove_rotten_apples);
+    /// ```
+    pub fn despawn_if(&mut self, predicate: impl FnOnce(&T) -> bool + Send + 'static) {
+        self.0.queue(move |entity: Entity, world: &mut World| {
+            if world.get::<T>(entity).is_some_and(predicate) {
+                world.despawn(entity);
+            }
+        });
+    }
+}
+
+impl<T: Kind> InstanceCommands<'_, T> {
+    /// Spawns a clone of this instance and returns its [`InstanceCommands<T>`].
+    ///
+    /// # Usage
+    /// All components of this instance which are registered for reflection (with [`ReflectComponent`]) in the
+    /// [`AppTypeRegistry`] are cloned when this command is applied. Any other components are ignored.
+    ///
+    /// Components which reference other entities (such as hierarchy components) are cloned as-is.
+    /// This means relationships are not updated, so the clone may reference entities which do not reference it back.
+    ///
+    /// # Panics
+    /// If the clone is not of kind `T` after all reflected components are cloned.
+    /// This may happen if any component required by `T` is not registered for reflection.
+    pub fn clone_instance(&mut self) -> InstanceCommands<'_, T> {
+        let instance = self.instance();
+        let mut clone = self.0.commands_mut().spawn_empty();
+        clone.queue(move |clone: Entity, world: &mut World| {
+            clone_instance_into(world, instance, clone);
+        });
+        // SAFE: `clone` must be a valid instance of kind `T` once the command is applied.
+        unsafe { InstanceCommands::from_entity_unchecked(clone) }
+    }
+}
+
+/// Returns the message logged by [`InstanceCommands::log_components`], or `None` if `entity` does not exist.
+pub(crate) fn components_message<T: Kind>(world: &World, entity: Entity) -> Option<String> {
+    world.get_entity(entity).ok()?;
+    let names: Vec<_> = world
+        .inspect_entity(entity)
+        .map(|info| moonshine_util::get_short_name(info.name()))
+        .collect();
+    // SAFE: `entity` must be a valid instance of kind `T`.
+    let instance = unsafe { Instance::<T>::from_entity_unchecked(entity) };
+    Some(format!("{instance:?} has components: {names:?}"))
+}
+
+/// Clones all reflected components of `instance` into `target` and ensures it is of kind `T`.
+pub(crate) fn clone_instance_into<T: Kind>(
+    world: &mut World,
+    instance: Instance<T>,
+    target: Entity,
+) {
+    if let Some(registry) = world.get_resource::<AppTypeRegistry>().cloned() {
+        let registry = registry.read();
+        let components: Vec<_> = world
+            .get_entity(instance.entity())
+            .map(|entity| {
+                entity
+                    .archetype()
+                    .components()
+                    .filter_map(|id| {
+                        let type_id = world.components().get_info(id)?.type_id()?;
+                        let reflect = registry.get_type_data::<ReflectComponent>(type_id)?;
+                        let value = reflect.reflect(entity)?.clone_value();
+                        Some((reflect.clone(), value))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut target = world.entity_mut(target);
+        for (reflect, value) in components {
+            reflect.insert(&mut target, value.as_partial_reflect(), &registry);
+        }
+    }
+
+    crate::register_kind_components::<T>(world);
+    assert!(
+        crate::is_kind::<T>(world, target),
+        "clone of {instance:?} is not of kind {}; are all of its components registered for reflection?",
+        T::debug_name()
+    );
+}
+
+impl<T: Kind> ContainsInstance<T> for InstanceCommands<'_, T> {
+    fn instance(&self) -> Instance<T> {
+        self.to_instance()
+    }
+}
+
+impl<T: Kind> AsEntity for InstanceCommands<'_, T> {
+    fn entity(&self) -> Entity {
+        InstanceCommands::entity(self)
+    }
+}
+
+impl<'a, T: Kind> From<InstanceCommands<'a, T>> for Instance<T> {
+    fn from(commands: InstanceCommands<'a, T>) -> Self {
+        commands.instance()
+    }
+}
+
+impl<'a, T: Kind> From<&InstanceCommands<'a, T>> for Instance<T> {
+    fn from(commands: &InstanceCommands<'a, T>) -> Self {
+        commands.instance()
+    }
+}
+
+impl<'a, T: Kind> Deref for InstanceCommands<'a, T> {
     type Target = EntityCommands<'a>;
 
     fn deref(&self) -> &Self::Target {
@@ -767,3 +2984,148 @@ impl<T: Kind> DerefMut for InstanceCommands<'_, T> {
         &mut self.0
     }
 }
+
+/// [`EntityWorldMut`] with kind semantics.
+///
+/// # Usage
+/// This type is the [`World`] equivalent of [`InstanceCommands<T>`].
+/// It allows structural changes to an [`Instance<T>`] (such as inserting components or adding children)
+/// while keeping its kind.
+///
+/// See [`SpawnInstanceWorld::spawn_instance_entity`][`crate::SpawnInstanceWorld::spawn_instance_entity`] for more information.
+pub struct InstanceWorldMut<'w, T: Kind>(EntityWorldMut<'w>, PhantomData<T>);
+
+impl<'w, T: Kind> InstanceWorldMut<'w, T> {
+    /// Creates a new [`InstanceWorldMut<T>`] from [`EntityWorldMut`] without any validation.
+    ///
+    /// # Safety
+    /// Assumes `entity` is a valid instance of kind `T`.
+    pub unsafe fn from_entity_unchecked(entity: EntityWorldMut<'w>) -> Self {
+        Self(entity, PhantomData)
+    }
+
+    /// Returns the associated [`Instance<T>`].
+    pub fn instance(&self) -> Instance<T> {
+        // SAFE: `self.entity()` must be a valid instance of kind `T`.
+        unsafe { Instance::from_entity_unchecked(self.entity()) }
+    }
+
+    /// Returns the associated [`Entity`].
+    pub fn entity(&self) -> Entity {
+        self.0.id()
+    }
+
+    /// Returns the associated [`EntityWorldMut`].
+    pub fn as_entity(&mut self) -> &mut EntityWorldMut<'w> {
+        &mut self.0
+    }
+
+    pub fn insert(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.0.insert(bundle);
+        self
+    }
+
+    pub fn remove<U: Component>(&mut self) -> &mut Self {
+        self.0.remove::<U>();
+        self
+    }
+
+    /// Converts this instance into an instance of another kind [`Kind`] `U`.
+    ///
+    /// In debug builds, this function panics if the entity is not of kind `U`.
+    pub fn cast_into<U: Kind>(self) -> InstanceWorldMut<'w, U>
+    where
+        T: CastInto<U>,
+    {
+        debug_assert!(
+            !crate::is_kind_mismatch::<U>(self.0.world(), self.0.id()),
+            "{} is not of kind {}",
+            self.0.id(),
+            U::debug_name()
+        );
+        // SAFE: `CastInto<U>` is implemented for `T`.
+        unsafe { InstanceWorldMut::from_entity_unchecked(self.0) }
+    }
+
+    /// Returns this instance as an [`InstanceWorldMut<U>`] if it has a [`Component`] of type `U`.
+    ///
+    /// This is the runtime-checked counterpart to [`cast_into`](Self::cast_into).
+    ///
+    /// There is no [`InstanceCommands`] equivalent, because the presence of `U` cannot be known
+    /// until all queued commands are applied.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Ripe;
+    ///
+    /// let mut world = World::new();
+    /// let mut apple = world.spawn_instance_entity(Apple);
+    /// apple.insert(Ripe);
+    /// assert!(apple.cast_into_component::<Ripe>().is_some());
+    /// ```
+    pub fn cast_into_component<U: Component>(self) -> Option<InstanceWorldMut<'w, U>> {
+        if !self.0.contains::<U>() {
+            return None;
+        }
+        // SAFE: Entity is checked to have `U`.
+        Some(unsafe { InstanceWorldMut::from_entity_unchecked(self.0) })
+    }
+
+    /// Returns all children of this instance which are of [`Kind`] `C`, in order.
+    ///
+    /// See [`Instance::children_of_kind`] for more information.
+    pub fn children_of_kind<C: Kind>(&self) -> Vec<Instance<C>> {
+        self.instance().children_of_kind(self.0.world())
+    }
+}
+
+impl<T: Kind> ContainsInstance<T> for InstanceWorldMut<'_, T> {
+    fn instance(&self) -> Instance<T> {
+        InstanceWorldMut::instance(self)
+    }
+}
+
+impl<T: Kind> AsEntity for InstanceWorldMut<'_, T> {
+    fn entity(&self) -> Entity {
+        InstanceWorldMut::entity(self)
+    }
+}
+
+impl<T: Kind> From<InstanceWorldMut<'_, T>> for Instance<T> {
+    fn from(entity: InstanceWorldMut<'_, T>) -> Self {
+        entity.instance()
+    }
+}
+
+impl<T: Kind> From<&InstanceWorldMut<'_, T>> for Instance<T> {
+    fn from(entity: &InstanceWorldMut<'_, T>) -> Self {
+        entity.instance()
+    }
+}
+
+impl<'w, T: Kind> Deref for InstanceWorldMut<'w, T> {
+    type Target = EntityWorldMut<'w>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T: Kind> DerefMut for InstanceWorldMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T: Kind> fmt::Debug for InstanceWorldMut<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.instance())
+    }
+}
@@ -1,12 +1,30 @@
 #![doc = include_str!("../README.md")]
 
-use bevy_ecs::{prelude::*, query::QueryFilter};
+use std::{any::TypeId, marker::PhantomData};
+
+use bevy_ecs::{
+    component::{ComponentId, Components},
+    prelude::*,
+    query::{QueryFilter, WorldQuery},
+    system::SystemParam,
+};
+use bevy_hierarchy::Children;
+use bevy_reflect::TypePath;
 
 pub mod prelude {
-    pub use crate::{kind, Kind, OfKind};
-    pub use crate::{GetInstanceCommands, InstanceCommands};
-    pub use crate::{Instance, InstanceMut, InstanceRef};
-    pub use crate::{KindBundle, SpawnInstance, SpawnInstanceWorld};
+    pub use crate::{kind, Kind, KindBundle, KindPlugin, Kinds, OfKind, RegisterKind};
+    pub use crate::{AddInstanceEvent, InstanceEventReader};
+    pub use crate::{AsEntity, AsInstance, ContainsInstance, InstancesOf, IntoInstanceAny};
+    pub use crate::{CollectInstanceSet, ComponentInstance, ComponentInstanceMut};
+    pub use crate::{DespawnInstanceWorld, DespawnInstances, SpawnInstance, SpawnInstanceWorld};
+    pub use crate::{DynInstance, DynKindWorld};
+    pub use crate::{GetEntityInstanceCommands, GetInstanceCommands};
+    pub use crate::{GetInstance, GetSingleInstance, HookInstance};
+    pub use crate::{GetTriggerTargetInstance, TriggerInstances, TriggerInstancesWorld};
+    pub use crate::{Instance, InstanceMut, InstanceRef, InstanceWorldMut};
+    pub use crate::{InstanceChildrenWorld, IsKind, IsKindWorld};
+    pub use crate::{InstanceCommands, IntoInstanceCommands};
+    pub use crate::{SendInstanceEvent, SendInstanceEventWorld};
 }
 
 /// A type which represents the kind of an [`Entity`].
@@ -47,7 +65,16 @@ pub trait Kind: 'static + Send + Sized + Sync {
     ///
     /// By default, this is the short type name (without path) of this kind.
     fn debug_name() -> String {
-        moonshine_util::get_short_name(std::any::type_name::<Self>())
+        moonshine_util::get_short_name(Self::type_path())
+    }
+
+    /// Returns the full type path of this kind.
+    ///
+    /// Unlike [`Kind::debug_name`], this is unambiguous when multiple modules define kinds with the same name.
+    ///
+    /// By default, this is the full type name (with path) of this kind.
+    fn type_path() -> &'static str {
+        std::any::type_name::<Self>()
     }
 }
 
@@ -58,15 +85,320 @@ impl<T: Component> Kind for T {
 /// Represents the kind of any [`Entity`].
 ///
 /// See [`Instance<Any>`] for more information on usage.
+#[derive(TypePath)]
 pub struct Any;
 
 impl Kind for Any {
     type Filter = ();
 }
 
+/// Represents the kind of any [`Entity`] which does not have a [`Component`] of type `T`.
+///
+/// # Usage
+/// Bevy does not support negation of arbitrary query filters (such as [`Or`]).
+/// Because of this, only component kinds (where the filter is [`With<T>`]) may be negated.
+/// The filter of `NotKind<T>` is [`Without<T>`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, NotKind};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// fn not_apples(query: Query<Instance<NotKind<Apple>>>) {
+///     for entity in query.iter() {
+///         println!("{entity:?} is not an apple!"); // "!Apple(..)"
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(not_apples);
+/// ```
+pub struct NotKind<T: Component>(PhantomData<T>);
+
+impl<T: Component> Kind for NotKind<T> {
+    type Filter = Without<T>;
+
+    fn debug_name() -> String {
+        format!("!{}", T::debug_name())
+    }
+}
+
+/// Represents the kind of any [`Entity`] which is of both [`Kind`] `A` and `B`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, KindAnd};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Ripe;
+///
+/// fn ripe_apples(query: Query<Instance<KindAnd<Apple, Ripe>>>) {
+///     for apple in query.iter() {
+///         println!("{apple:?} is ripe!"); // "Apple&Ripe(..)"
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(ripe_apples);
+/// ```
+pub struct KindAnd<A: Kind, B: Kind>(PhantomData<(A, B)>);
+
+impl<A: Kind, B: Kind> Kind for KindAnd<A, B> {
+    type Filter = (A::Filter, B::Filter);
+
+    fn debug_name() -> String {
+        format!("{}&{}", A::debug_name(), B::debug_name())
+    }
+}
+
+/// Represents the kind of any [`Entity`] which is of either [`Kind`] `A` or `B`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, KindOr};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Orange;
+///
+/// struct Fruit;
+///
+/// impl Kind for Fruit {
+///     type Filter = OfKind<KindOr<Apple, Orange>>;
+/// }
+///
+/// fn fruits(query: Query<Instance<KindOr<Apple, Orange>>>) {
+///     for fruit in query.iter() {
+///         println!("{fruit:?} is a fruit!"); // "Apple|Orange(..)"
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(fruits);
+/// ```
+pub struct KindOr<A: Kind, B: Kind>(PhantomData<(A, B)>);
+
+impl<A: Kind, B: Kind> Kind for KindOr<A, B> {
+    type Filter = Or<(A::Filter, B::Filter)>;
+
+    fn debug_name() -> String {
+        format!("{}|{}", A::debug_name(), B::debug_name())
+    }
+}
+
+/// Represents the kind of any [`Entity`] which matches the [`QueryFilter`] `F`.
+///
+/// # Usage
+/// This is useful for quick one-off kinds (such as in tests and prototypes) where declaring a named kind is unnecessary.
+/// The debug name of `FilterKind<F>` is the short type name of `F`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, FilterKind};
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Orange;
+///
+/// type Fruit = FilterKind<Or<(With<Apple>, With<Orange>)>>;
+///
+/// fn fruits(query: Query<Instance<Fruit>>) {
+///     for fruit in query.iter() {
+///         println!("{fruit:?} is a fruit!"); // "Or<(With<Apple>, With<Orange>)>(..)"
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(fruits);
+/// ```
+pub struct FilterKind<F: QueryFilter + 'static>(PhantomData<fn() -> F>);
+
+impl<F: QueryFilter + 'static> Kind for FilterKind<F> {
+    type Filter = F;
+
+    fn debug_name() -> String {
+        moonshine_util::get_short_name(std::any::type_name::<F>())
+    }
+}
+
+/// A [`QueryFilter`] which matches instances of [`Kind`] `T` whose [`Component`] has changed.
+///
+/// # Usage
+/// Change detection only applies to components, so this filter is only available for component kinds.
+/// Filter-based kinds (such as `Fruit = Or<(With<Apple>, With<Orange>)>`) have no notion of change and
+/// are rejected at compile time, since `T` must be a [`Component`].
+///
+/// This filter is equivalent to [`Changed<T>`], expressed in kind terms.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, ChangedKind};
+///
+/// #[derive(Component)]
+/// struct Apple {
+///     freshness: f32,
+/// }
+///
+/// fn changed_apples(query: Query<Instance<Apple>, ChangedKind<Apple>>) {
+///     for apple in query.iter() {
+///         println!("{apple:?} has changed!");
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(changed_apples);
+/// ```
+pub type ChangedKind<T> = Changed<T>;
+
+/// Returns `true` if the given [`Entity`] matches the archetypal filter of [`Kind`] `T`.
+pub(crate) fn is_kind<T: Kind>(world: &World, entity: Entity) -> bool {
+    kind_matcher::<T>(world)(entity)
+}
+
+/// Returns a function which returns `true` if a given [`Entity`] matches the archetypal filter of [`Kind`] `T`.
+pub(crate) fn kind_matcher<T: Kind>(world: &World) -> impl Fn(Entity) -> bool + '_ {
+    move |entity| {
+        world.get_entity(entity).is_ok_and(|entity| {
+            matches_kind::<T>(world.components(), |id| entity.contains_id(id)) == Some(true)
+        })
+    }
+}
+
+/// Returns `true` if the given [`Entity`] exists and is known to not match the archetypal filter of [`Kind`] `T`.
+///
+/// Unlike `!is_kind`, this returns `false` if any component referenced by the filter is not registered,
+/// since the filter cannot be evaluated. This is used in debug builds to validate explicit casts.
+pub(crate) fn is_kind_mismatch<T: Kind>(world: &World, entity: Entity) -> bool {
+    world.get_entity(entity).is_ok_and(|entity| {
+        matches_kind::<T>(world.components(), |id| entity.contains_id(id)) == Some(false)
+    })
+}
+
+/// Returns whether the given component set matches the archetypal filter of [`Kind`] `T`.
+///
+/// Returns `None` if any component referenced by the filter is not registered in `components`.
+fn matches_kind<T: Kind>(
+    components: &Components,
+    contains_id: impl Fn(ComponentId) -> bool,
+) -> Option<bool> {
+    let state = <T::Filter as WorldQuery>::get_state(components)?;
+    Some(<T::Filter as WorldQuery>::matches_component_set(
+        &state,
+        &contains_id,
+    ))
+}
+
+/// Registers all components referenced by the filter of [`Kind`] `T`, so that it may be evaluated by [`is_kind`].
+pub(crate) fn register_kind_components<T: Kind>(world: &mut World) {
+    <T::Filter as WorldQuery>::init_state(world);
+}
+
+/// Returns `true` if the filter of [`Kind`] `T` is anything other than [`With<T>`].
+///
+/// Only instances of such kinds may be spawned without matching their own filter.
+fn has_custom_filter<T: Kind>() -> bool {
+    TypeId::of::<T::Filter>() != TypeId::of::<With<T>>()
+}
+
+/// Logs a warning if a newly spawned `entity` does not match the filter of its [`Kind`] `T`.
+///
+/// This is used in debug builds to catch kinds which require more than their [`KindBundle`] provides.
+fn warn_if_not_kind<T: Kind>(entity: Entity, world: &mut World) {
+    if is_kind_mismatch::<T>(world, entity) {
+        bevy_utils::tracing::warn!(
+            "{entity} was spawned as an instance of {}, but does not match its filter",
+            T::debug_name()
+        );
+    }
+}
+
+/// Extension trait to check the [`Kind`] of an [`EntityRef`].
+pub trait IsKind {
+    /// Returns `true` if this entity is of [`Kind`] `T`.
+    ///
+    /// # Usage
+    /// This function evaluates `T::Filter` against the archetype of this entity, so it works for any kind,
+    /// including kinds defined using [`Or`] and [`Without`] filters.
+    /// It is useful when an [`EntityRef`] is already available and a full [`Query`] is not.
+    ///
+    /// Note that only archetypal filters (such as [`With`], [`Without`] and [`Or`]) are evaluated.
+    /// Change detection filters (such as [`Added`] and [`Changed`]) are ignored.
+    /// If any component referenced by `T::Filter` is not registered in the world, the filter cannot be evaluated
+    /// and this returns `false`. Use [`IsKindWorld::is_kind`] to register them first.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Orange;
+    ///
+    /// struct Fruit;
+    ///
+    /// impl Kind for Fruit {
+    ///     type Filter = Or<(With<Apple>, With<Orange>)>;
+    /// }
+    ///
+    /// fn count_fruits(world: &World) -> usize {
+    ///     world.iter_entities().filter(|entity| entity.is_kind::<Fruit>(world)).count()
+    /// }
+    /// ```
+    fn is_kind<T: Kind>(&self, world: &World) -> bool;
+}
+
+impl IsKind for EntityRef<'_> {
+    fn is_kind<T: Kind>(&self, world: &World) -> bool {
+        matches_kind::<T>(world.components(), |id| self.contains_id(id)) == Some(true)
+    }
+}
+
+/// Extension trait to check the [`Kind`] of an [`EntityWorldMut`].
+pub trait IsKindWorld {
+    /// Returns `true` if this entity is of [`Kind`] `T`.
+    ///
+    /// Unlike [`IsKind::is_kind`], this also registers any components referenced by `T::Filter`.
+    ///
+    /// See [`IsKind::is_kind`] for more information.
+    fn is_kind<T: Kind>(&mut self) -> bool;
+}
+
+impl IsKindWorld for EntityWorldMut<'_> {
+    fn is_kind<T: Kind>(&mut self) -> bool {
+        let entity = self.id();
+        self.world_scope(|world| {
+            register_kind_components::<T>(world);
+            is_kind::<T>(world, entity)
+        })
+    }
+}
+
+mod collections;
+mod dynamic;
+mod event;
 mod instance;
+mod registry;
 
+#[cfg(any(test, feature = "test-support"))]
+pub mod test_support;
+
+pub use collections::*;
+pub use dynamic::*;
+pub use event::*;
 pub use instance::*;
+pub use registry::*;
 
 /// A trait which allows safe casting from one [`Kind`] to another.
 ///
@@ -125,6 +457,42 @@ macro_rules! kind {
     };
 }
 
+/// A marker trait for a [`Kind`] with exactly the same [`Filter`][`Kind::Filter`] as another kind `T`.
+///
+/// # Usage
+/// This trait is implemented automatically for any pair of kinds with the same filter type.
+/// Any entity which matches one of these kinds must also match the other, so an instance of one
+/// may always be safely reinterpreted as an instance of the other using [`Instance::reinterpret`].
+///
+/// Note that two filters which are *logically* equivalent, but not the same type
+/// (such as `(With<A>, With<B>)` and `(With<B>, With<A>)`), are not considered the same.
+///
+/// Prefer [`CastInto`] (see [`kind`] macro) when one kind is a subset of another (i.e. "an `Apple` is a `Fruit`"),
+/// since the conversion is only valid in one direction.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// struct Living;
+///
+/// impl Kind for Living {
+///     type Filter = With<Health>;
+/// }
+///
+/// fn heal(health: Instance<Health>) -> Instance<Living> {
+///     // All entities with `Health` are `Living`, and vice versa:
+///     health.reinterpret()
+/// }
+/// ```
+pub trait SameKind<T: Kind>: Kind {}
+
+impl<T: Kind, U: Kind<Filter = T::Filter>> SameKind<T> for U {}
+
 /// A short alias for using a [`Kind`] as a [`QueryFilter`].
 ///
 /// # Example
@@ -143,6 +511,69 @@ macro_rules! kind {
 /// ```
 pub type OfKind<T> = <T as Kind>::Filter;
 
+/// A [`SystemParam`] which provides access to all instances of [`Kind`] `T`.
+///
+/// # Usage
+/// This is a thin wrapper around [`Query<Instance<T>>`][`Query`] with kind-centric accessors.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::prelude::*;
+///
+/// #[derive(Component)]
+/// struct Apple;
+///
+/// #[derive(Component)]
+/// struct Orange;
+///
+/// struct Fruit;
+///
+/// impl Kind for Fruit {
+///     type Filter = Or<(With<Apple>, With<Orange>)>;
+/// }
+///
+/// fn count_fruits(fruits: Kinds<Fruit>) {
+///     println!("There are {} fruits:", fruits.count());
+///     for fruit in fruits.iter() {
+///         println!("{fruit:?}");
+///     }
+/// }
+///
+/// # bevy_ecs::system::assert_is_system(count_fruits);
+/// ```
+#[derive(SystemParam)]
+pub struct Kinds<'w, 's, T: Kind> {
+    query: Query<'w, 's, Instance<T>>,
+}
+
+impl<T: Kind> Kinds<'_, '_, T> {
+    /// Returns an iterator over all instances of kind `T`.
+    pub fn iter(&self) -> impl Iterator<Item = Instance<T>> + '_ {
+        self.query.iter()
+    }
+
+    /// Returns `true` if the given [`Instance<T>`] is still a valid instance of kind `T`.
+    pub fn contains(&self, instance: Instance<T>) -> bool {
+        self.query.contains(instance.entity())
+    }
+
+    /// Returns the number of instances of kind `T`.
+    pub fn count(&self) -> usize {
+        self.query.iter().count()
+    }
+
+    /// Returns an [`Instance<T>`] for the given [`Entity`] if it is of kind `T`.
+    pub fn get(&self, entity: Entity) -> Option<Instance<T>> {
+        self.query.get(entity).ok()
+    }
+
+    /// Returns `true` if there are no instances of kind `T`.
+    pub fn is_empty(&self) -> bool {
+        self.query.is_empty()
+    }
+}
+
 /// A [`Bundle`] which represents a [`Kind`].
 ///
 /// # Usage
@@ -164,6 +595,8 @@ impl<T: Kind + Bundle> KindBundle for T {
 pub trait SpawnInstance {
     /// Spawns a new [`Instance<T>`] using its associated [`KindBundle`].
     ///
+    /// In debug builds, a warning is logged if the spawned entity does not match the filter of `T::Kind`.
+    ///
     /// # Example
     /// ```
     /// # use bevy::prelude::*;
@@ -179,13 +612,58 @@ pub trait SpawnInstance {
     ///
     /// # bevy_ecs::system::assert_is_system(spawn_apple);
     fn spawn_instance<T: KindBundle>(&mut self, _: T) -> InstanceCommands<'_, T::Kind>;
+
+    /// Spawns a new [`Instance<T>`] using its associated [`KindBundle`], configures it with the given function,
+    /// and returns the spawned [`Instance<T>`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Seed;
+    ///
+    /// fn spawn_apple(mut commands: Commands) {
+    ///     let apple: Instance<Apple> = commands.spawn_instance_with(Apple, |apple| {
+    ///         apple.insert(Name::new("Fuji"));
+    ///         apple.with_children(|apple| {
+    ///             apple.spawn(Seed);
+    ///         });
+    ///     });
+    ///     println!("Spawned {apple:?}!");
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(spawn_apple);
+    /// ```
+    fn spawn_instance_with<T: KindBundle>(
+        &mut self,
+        bundle: T,
+        f: impl FnOnce(&mut InstanceCommands<T::Kind>),
+    ) -> Instance<T::Kind>;
 }
 
 impl SpawnInstance for Commands<'_, '_> {
     fn spawn_instance<T: KindBundle>(&mut self, bundle: T) -> InstanceCommands<'_, T::Kind> {
-        let entity = self.spawn(bundle).id();
+        let mut entity = self.spawn(bundle);
+        if cfg!(debug_assertions) && has_custom_filter::<T::Kind>() {
+            entity.queue(warn_if_not_kind::<T::Kind>);
+        }
         // SAFE: `entity` must be a valid instance of `T::Kind`.
-        unsafe { InstanceCommands::from_entity_unchecked(self.entity(entity)) }
+        unsafe { InstanceCommands::from_entity_unchecked(entity) }
+    }
+
+    fn spawn_instance_with<T: KindBundle>(
+        &mut self,
+        bundle: T,
+        f: impl FnOnce(&mut InstanceCommands<T::Kind>),
+    ) -> Instance<T::Kind> {
+        let mut instance = self.spawn_instance(bundle);
+        f(&mut instance);
+        instance.to_instance()
     }
 }
 
@@ -193,6 +671,8 @@ impl SpawnInstance for Commands<'_, '_> {
 pub trait SpawnInstanceWorld {
     /// Spawns a new [`Instance<T>`] using its associated [`KindBundle`].
     ///
+    /// In debug builds, a warning is logged if the spawned entity does not match the filter of `T::Kind`.
+    ///
     /// # Example
     /// ```
     /// # use bevy::prelude::*;
@@ -208,16 +688,368 @@ pub trait SpawnInstanceWorld {
     fn spawn_instance<T: KindBundle>(&mut self, _: T) -> InstanceMutItem<'_, T::Kind>
     where
         T::Kind: Component;
-}
 
-impl SpawnInstanceWorld for World {
-    fn spawn_instance<T: KindBundle>(&mut self, bundle: T) -> InstanceMutItem<'_, T::Kind>
-    where
-        T::Kind: Component,
-    {
-        let entity = self.spawn(bundle).id();
-        // SAFE: `entity` must be a valid instance of kind `T`.
-        InstanceMutItem::from_entity(self, entity).unwrap()
+    /// Spawns a new [`Instance<T>`] using its associated [`KindBundle`] and returns its [`InstanceWorldMut<T>`].
+    ///
+    /// # Usage
+    /// Unlike [`SpawnInstanceWorld::spawn_instance`], this allows further structural changes to the
+    /// spawned instance, similar to [`SpawnInstance::spawn_instance`].
+    ///
+    /// In debug builds, a warning is logged if the spawned entity does not match the filter of `T::Kind`.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Component)]
+    /// struct Seed;
+    ///
+    /// fn spawn_apple(world: &mut World) -> Instance<Apple> {
+    ///     let mut apple = world.spawn_instance_entity(Apple);
+    ///     apple.with_children(|apple| {
+    ///         apple.spawn(Seed);
+    ///     });
+    ///     apple.instance()
+    /// }
+    /// ```
+    fn spawn_instance_entity<T: KindBundle>(&mut self, _: T) -> InstanceWorldMut<'_, T::Kind>;
+
+    /// Spawns a new [`Instance<T>`] using its associated [`KindBundle`], configures it with the given function,
+    /// and returns the spawned [`Instance<T>`].
+    ///
+    /// See [`SpawnInstance::spawn_instance_with`] for more information.
+    fn spawn_instance_with<T: KindBundle>(
+        &mut self,
+        bundle: T,
+        f: impl FnOnce(&mut InstanceWorldMut<T::Kind>),
+    ) -> Instance<T::Kind>;
+
+    /// Spawns a clone of the given [`Instance<T>`] and returns its [`InstanceWorldMut<T>`].
+    ///
+    /// See [`InstanceCommands::clone_instance`] for details on which components are cloned.
+    ///
+    /// # Panics
+    /// If the clone is not of kind `T` after all reflected components are cloned.
+    fn clone_instance<T: Kind>(&mut self, instance: Instance<T>) -> InstanceWorldMut<'_, T>;
+
+    /// Spawns a new [`Instance<C>`] as a child of the given parent [`Entity`] for each bundle in the given
+    /// iterator, and returns all spawned instances in order.
+    ///
+    /// # Usage
+    /// Unlike [`InstanceCommands::spawn_child_instances`], this spawns all children immediately in a single batch
+    /// and adds them to the parent at once, which avoids the overhead of queueing a command per child.
+    /// This is useful when spawning many children every frame.
+    ///
+    /// # Panics
+    /// If the parent entity does not exist.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Emitter;
+    ///
+    /// #[derive(Component)]
+    /// struct Particle(u32);
+    ///
+    /// let mut world = World::new();
+    /// let emitter = world.spawn_instance(Emitter).instance();
+    /// let particles = world.spawn_child_instances(emitter.entity(), (0..100).map(Particle));
+    /// assert_eq!(particles.len(), 100);
+    /// ```
+    fn spawn_child_instances<C: KindBundle>(
+        &mut self,
+        parent: Entity,
+        children: impl IntoIterator<Item = C>,
+    ) -> Vec<Instance<C::Kind>>;
+}
+
+impl SpawnInstanceWorld for World {
+    fn spawn_instance<T: KindBundle>(&mut self, bundle: T) -> InstanceMutItem<'_, T::Kind>
+    where
+        T::Kind: Component,
+    {
+        let entity = self.spawn(bundle).id();
+        if cfg!(debug_assertions) && has_custom_filter::<T::Kind>() {
+            warn_if_not_kind::<T::Kind>(entity, self);
+        }
+        // SAFE: `entity` must be a valid instance of kind `T`.
+        InstanceMutItem::from_entity(self, entity).unwrap()
+    }
+
+    fn spawn_instance_entity<T: KindBundle>(&mut self, bundle: T) -> InstanceWorldMut<'_, T::Kind> {
+        let entity = self.spawn(bundle).id();
+        if cfg!(debug_assertions) && has_custom_filter::<T::Kind>() {
+            warn_if_not_kind::<T::Kind>(entity, self);
+        }
+        // SAFE: `entity` must be a valid instance of `T::Kind`.
+        unsafe { InstanceWorldMut::from_entity_unchecked(self.entity_mut(entity)) }
+    }
+
+    fn spawn_instance_with<T: KindBundle>(
+        &mut self,
+        bundle: T,
+        f: impl FnOnce(&mut InstanceWorldMut<T::Kind>),
+    ) -> Instance<T::Kind> {
+        let mut instance = self.spawn_instance_entity(bundle);
+        f(&mut instance);
+        instance.instance()
+    }
+
+    fn clone_instance<T: Kind>(&mut self, instance: Instance<T>) -> InstanceWorldMut<'_, T> {
+        let clone = self.spawn_empty().id();
+        instance::clone_instance_into(self, instance, clone);
+        // SAFE: `clone` is validated to be of kind `T` by `clone_instance_into`.
+        unsafe { InstanceWorldMut::from_entity_unchecked(self.entity_mut(clone)) }
+    }
+
+    fn spawn_child_instances<C: KindBundle>(
+        &mut self,
+        parent: Entity,
+        children: impl IntoIterator<Item = C>,
+    ) -> Vec<Instance<C::Kind>> {
+        use bevy_hierarchy::BuildChildren;
+        assert!(self.get_entity(parent).is_ok(), "{parent} does not exist");
+        let entities: Vec<Entity> = self.spawn_batch(children).collect();
+        if cfg!(debug_assertions) && has_custom_filter::<C::Kind>() {
+            for &entity in &entities {
+                warn_if_not_kind::<C::Kind>(entity, self);
+            }
+        }
+        self.entity_mut(parent).add_children(&entities);
+        // SAFE: All entities are spawned with a bundle of `C::Kind`.
+        unsafe { entities.into_iter().instances_of_unchecked().collect() }
+    }
+}
+
+/// Extension trait to despawn an [`Instance<T>`] using [`World`].
+pub trait DespawnInstanceWorld {
+    /// Despawns the given [`Instance<T>`] and returns `true` if it existed.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// let mut world = World::new();
+    /// let apple = world.spawn_instance(Apple).instance();
+    /// assert!(world.despawn_instance(apple));
+    /// assert!(!world.despawn_instance(apple));
+    /// ```
+    fn despawn_instance<T: Kind>(&mut self, instance: Instance<T>) -> bool;
+
+    /// Despawns the given [`Instance<T>`] and all of its descendants, and returns `true` if it existed.
+    ///
+    /// See [`DespawnRecursiveExt`](bevy_hierarchy::DespawnRecursiveExt) for more information.
+    fn despawn_instance_recursive<T: Kind>(&mut self, instance: Instance<T>) -> bool;
+
+    /// Despawns every [`Instance<T>`].
+    ///
+    /// See [`DespawnInstances::despawn_instances`] for more information.
+    fn despawn_instances<T: Kind>(&mut self);
+
+    /// Despawns every [`Instance<T>`] and all of their descendants.
+    ///
+    /// See [`DespawnInstances::despawn_instances_recursive`] for more information.
+    fn despawn_instances_recursive<T: Kind>(&mut self);
+}
+
+impl DespawnInstanceWorld for World {
+    fn despawn_instance<T: Kind>(&mut self, instance: Instance<T>) -> bool {
+        self.despawn(instance.entity())
+    }
+
+    fn despawn_instance_recursive<T: Kind>(&mut self, instance: Instance<T>) -> bool {
+        use bevy_hierarchy::DespawnRecursiveExt;
+        self.get_entity_mut(instance.entity())
+            .map(|entity| entity.despawn_recursive())
+            .is_ok()
+    }
+
+    fn despawn_instances<T: Kind>(&mut self) {
+        for instance in self.run_system_cached(collect_instances::<T>).unwrap() {
+            self.despawn_instance(instance);
+        }
+    }
+
+    fn despawn_instances_recursive<T: Kind>(&mut self) {
+        for instance in self.run_system_cached(collect_instances::<T>).unwrap() {
+            // An instance may have already been despawned as a descendant of another instance.
+            if self.get_entity(instance.entity()).is_ok() {
+                self.despawn_instance_recursive(instance);
+            }
+        }
+    }
+}
+
+/// Extension trait to despawn all instances of a [`Kind`] using [`Commands`].
+pub trait DespawnInstances {
+    /// Despawns every [`Instance<T>`].
+    ///
+    /// # Usage
+    /// The instances are resolved when this command is applied, so any instances spawned or despawned by
+    /// commands queued before it are taken into account. Instances spawned by commands queued after it are not despawned.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Enemy;
+    ///
+    /// fn clear_enemies(mut commands: Commands) {
+    ///     commands.despawn_instances::<Enemy>();
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(clear_enemies);
+    /// ```
+    fn despawn_instances<T: Kind>(&mut self);
+
+    /// Despawns every [`Instance<T>`] and all of their descendants.
+    ///
+    /// See [`DespawnInstances::despawn_instances`] for more information.
+    fn despawn_instances_recursive<T: Kind>(&mut self);
+}
+
+impl DespawnInstances for Commands<'_, '_> {
+    fn despawn_instances<T: Kind>(&mut self) {
+        self.queue(|world: &mut World| world.despawn_instances::<T>());
+    }
+
+    fn despawn_instances_recursive<T: Kind>(&mut self) {
+        self.queue(|world: &mut World| world.despawn_instances_recursive::<T>());
+    }
+}
+
+fn collect_instances<T: Kind>(query: Query<Instance<T>>) -> Vec<Instance<T>> {
+    query.iter().collect()
+}
+
+/// Extension trait to iterate over the children of all instances of a [`Kind`] using [`World`].
+pub trait InstanceChildrenWorld {
+    /// Returns an iterator over each [`Instance<T>`] paired with each of its [`Children`] as an [`Instance<Any>`].
+    ///
+    /// # Usage
+    /// This avoids manually zipping [`Children`] with a query of the parent instances.
+    /// Use [`Instance::cast_checked`] or [`Instance::downcast_world`] to narrow the children, if needed.
+    ///
+    /// Instances without any children are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Tree;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// let mut world = World::new();
+    /// let tree = world.spawn_instance(Tree).instance();
+    /// world.entity_mut(tree.entity()).with_children(|tree| {
+    ///     tree.spawn(Apple);
+    ///     tree.spawn(Apple);
+    /// });
+    ///
+    /// for (tree, child) in world.instance_children::<Tree>() {
+    ///     assert!(child.cast_checked::<Apple>(&world).is_some());
+    /// }
+    /// ```
+    fn instance_children<T: Kind>(&self)
+        -> impl Iterator<Item = (Instance<T>, Instance<Any>)> + '_;
+}
+
+impl InstanceChildrenWorld for World {
+    fn instance_children<T: Kind>(
+        &self,
+    ) -> impl Iterator<Item = (Instance<T>, Instance<Any>)> + '_ {
+        let matches = kind_matcher::<T>(self);
+        self.iter_entities()
+            .filter_map(|entity| Some((entity.id(), entity.get::<Children>()?)))
+            .filter(move |&(entity, _)| matches(entity))
+            .flat_map(|(entity, children)| {
+                // SAFE: `entity` is checked to be of kind `T`.
+                let instance = unsafe { Instance::<T>::from_entity_unchecked(entity) };
+                children
+                    .iter()
+                    .map(move |&child| (instance, Instance::from(child)))
+            })
+    }
+}
+
+/// Extension trait to trigger an [`Event`] for all instances of a [`Kind`] using [`Commands`].
+pub trait TriggerInstances {
+    /// Triggers the given [`Event`] for every [`Instance<T>`].
+    ///
+    /// # Usage
+    /// This is equivalent to [`Commands::trigger_targets`] where the targets are all entities which match `T::Filter`.
+    /// Because of this, any observer of this event is guaranteed to be triggered only for entities of kind `T`.
+    ///
+    /// The targets are resolved when this command is applied, so any instances spawned or despawned by
+    /// commands queued before it are taken into account. If there are no instances, no observers are triggered.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use moonshine_kind::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Apple;
+    ///
+    /// #[derive(Event)]
+    /// struct Rot;
+    ///
+    /// fn rot_apples(mut commands: Commands) {
+    ///     commands.trigger_instances::<Apple, _>(Rot);
+    /// }
+    ///
+    /// # bevy_ecs::system::assert_is_system(rot_apples);
+    /// ```
+    fn trigger_instances<T: Kind, E: Event>(&mut self, event: E);
+}
+
+impl TriggerInstances for Commands<'_, '_> {
+    fn trigger_instances<T: Kind, E: Event>(&mut self, event: E) {
+        self.run_system_cached_with(trigger_instances::<T, E>, event);
+    }
+}
+
+/// Extension trait to trigger an [`Event`] for all instances of a [`Kind`] using [`World`].
+pub trait TriggerInstancesWorld {
+    /// Triggers the given [`Event`] for every [`Instance<T>`].
+    ///
+    /// See [`TriggerInstances::trigger_instances`] for more information.
+    fn trigger_instances<T: Kind, E: Event>(&mut self, event: E);
+}
+
+impl TriggerInstancesWorld for World {
+    fn trigger_instances<T: Kind, E: Event>(&mut self, event: E) {
+        self.run_system_cached_with(trigger_instances::<T, E>, event)
+            .unwrap();
+    }
+}
+
+fn trigger_instances<T: Kind, E: Event>(
+    In(event): In<E>,
+    query: Query<Instance<T>>,
+    mut commands: Commands,
+) {
+    let targets: Vec<Entity> = query.iter().map(|instance| instance.entity()).collect();
+    // Triggering with no targets would trigger all global observers instead:
+    if !targets.is_empty() {
+        commands.trigger_targets(event, targets);
     }
 }
 
@@ -240,6 +1072,25 @@ mod tests {
         assert_eq!(world.run_system_once(count::<Foo>).unwrap(), 1);
     }
 
+    #[test]
+    fn has_custom_filter() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        struct RipeApple;
+
+        impl Kind for RipeApple {
+            type Filter = (With<Apple>, With<Ripe>);
+        }
+
+        assert!(!super::has_custom_filter::<Apple>());
+        assert!(super::has_custom_filter::<RipeApple>());
+        assert!(super::has_custom_filter::<Any>());
+    }
+
     #[test]
     fn kind_without() {
         #[derive(Component)]
@@ -270,6 +1121,129 @@ mod tests {
         assert_eq!(world.run_system_once(count::<Bar>).unwrap(), 1);
     }
 
+    #[test]
+    fn kind_sparse() {
+        #[derive(Component)]
+        #[component(storage = "SparseSet")]
+        struct Foo;
+
+        #[derive(Component)]
+        struct Bar;
+
+        #[derive(Component)]
+        struct Baz;
+
+        struct FooOrBaz;
+
+        impl Kind for FooOrBaz {
+            type Filter = Or<(With<Foo>, With<Baz>)>;
+        }
+
+        let mut world = World::new();
+        let a = world.spawn(Foo).id();
+        let b = world.spawn((Foo, Bar)).id();
+        let c = world.spawn((Foo, Baz)).id();
+        let d = world.spawn(Baz).id();
+        world.spawn(Bar);
+
+        let mut query = world.query::<Instance<Foo>>();
+        let mut foos: Vec<_> = query.iter(&world).map(|foo| foo.entity()).collect();
+        foos.sort();
+        assert_eq!(foos, [a, b, c]);
+
+        let mut query = world.query::<Instance<FooOrBaz>>();
+        let mut foos: Vec<_> = query.iter(&world).map(|foo| foo.entity()).collect();
+        foos.sort();
+        assert_eq!(foos, [a, b, c, d]);
+    }
+
+    #[test]
+    fn kind_filter() {
+        #[derive(Component)]
+        struct Foo;
+
+        #[derive(Component)]
+        struct Bar(u32);
+
+        let mut world = World::new();
+        world.spawn((Foo, Bar(1)));
+        world.spawn(Bar(2));
+        world.spawn(Foo);
+
+        let mut query = world.query_filtered::<&Bar, Instance<Foo>>();
+        let bars: Vec<_> = query.iter(&world).map(|bar| bar.0).collect();
+        assert_eq!(bars, [1]);
+
+        let mut query = world.query_filtered::<&Bar, Instance<NotKind<Foo>>>();
+        let bars: Vec<_> = query.iter(&world).map(|bar| bar.0).collect();
+        assert_eq!(bars, [2]);
+    }
+
+    #[test]
+    fn filter_kind() {
+        #[derive(Component)]
+        struct Foo;
+
+        #[derive(Component)]
+        struct Bar;
+
+        type FooOrBar = FilterKind<Or<(With<Foo>, With<Bar>)>>;
+
+        let mut world = World::new();
+        let foo = world.spawn(Foo).id();
+        let bar = world.spawn(Bar).id();
+        world.spawn_empty();
+
+        let mut query = world.query::<Instance<FooOrBar>>();
+        let entities: Vec<_> = query.iter(&world).map(|i| i.entity()).collect();
+        assert_eq!(entities, [foo, bar]);
+        assert_eq!(FooOrBar::debug_name(), "Or<(With<Foo>, With<Bar>)>");
+    }
+
+    #[test]
+    fn kind_not() {
+        #[derive(Component)]
+        struct Foo;
+
+        #[derive(Component)]
+        struct Bar;
+
+        let mut world = World::new();
+        world.spawn(Foo);
+        world.spawn(Bar);
+        world.spawn((Foo, Bar));
+        let all = world.run_system_once(count::<Any>).unwrap();
+        let foo = world.run_system_once(count::<Foo>).unwrap();
+        let not_foo = world.run_system_once(count::<NotKind<Foo>>).unwrap();
+        assert_eq!(foo, 2);
+        assert_eq!(not_foo, 1);
+        assert_eq!(foo + not_foo, all);
+    }
+
+    #[test]
+    fn kind_and_or() {
+        #[derive(Component)]
+        struct Foo;
+
+        #[derive(Component)]
+        struct Bar;
+
+        let mut world = World::new();
+        world.spawn(Foo);
+        world.spawn(Bar);
+        world.spawn((Foo, Bar));
+        assert_eq!(
+            world.run_system_once(count::<KindAnd<Foo, Bar>>).unwrap(),
+            1
+        );
+        assert_eq!(world.run_system_once(count::<KindOr<Foo, Bar>>).unwrap(), 3);
+        assert_eq!(KindAnd::<Foo, Bar>::debug_name(), "Foo&Bar");
+        assert_eq!(
+            KindOr::<Foo, KindAnd<Foo, Bar>>::debug_name(),
+            "Foo|Foo&Bar"
+        );
+    }
+
     #[test]
     fn kind_cast() {
         #[derive(Component)]
@@ -288,5 +1262,1458 @@ mod tests {
         // assert!(any.cast_into::<Foo>() == foo); // <-- Must not compile!
         // assert!(bar.cast_into::<Foo>() == foo); // <-- Must not compile!
         assert!(bar.entity() == foo.entity());
+        assert!(bar.same_entity(&foo));
+        assert!(!foo.same_entity(&Instance::<Any>::from(Entity::from_raw(1))));
+    }
+
+    #[test]
+    fn kind_reinterpret() {
+        #[derive(Component)]
+        struct Foo;
+
+        struct Bar;
+
+        impl Kind for Bar {
+            type Filter = With<Foo>;
+        }
+
+        let foo = Instance::<Foo>::PLACEHOLDER;
+        let bar = foo.reinterpret::<Bar>();
+        assert!(bar.reinterpret::<Foo>() == foo);
+        // assert!(foo.reinterpret::<Any>() == foo.cast_into_any()); // <-- Must not compile!
+    }
+
+    #[test]
+    fn kind_is_kind() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = Or<(With<Apple>, With<Orange>)>;
+        }
+
+        kind!(Apple is Fruit);
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        let fruit: Instance<Fruit> = apple.cast_into();
+
+        // `Orange` is not registered yet, so filters which reference it cannot be evaluated:
+        assert!(fruit.is_kind::<Apple>(&world));
+        assert!(!fruit.is_kind::<Fruit>(&world));
+        assert!(!fruit.is_kind::<NotKind<Orange>>(&world));
+
+        world.register_component::<Orange>();
+        assert!(fruit.is_kind::<Apple>(&world));
+        assert!(fruit.is_kind::<Fruit>(&world));
+        assert!(!fruit.is_kind::<Orange>(&world));
+        assert!(fruit.is_kind::<NotKind<Orange>>(&world));
+    }
+
+    #[test]
+    fn kind_cast_vec() {
+        #[derive(Component)]
+        struct Apple;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = With<Apple>;
+        }
+
+        kind!(Apple is Fruit);
+
+        let mut world = World::new();
+        let apples: Vec<Instance<Apple>> = (0..10_000)
+            .map(|_| world.spawn_instance(Apple).instance())
+            .collect();
+        let expected: Vec<Entity> = apples.iter().map(|apple| apple.entity()).collect();
+
+        let fruits: &[Instance<Fruit>] = Instance::cast_slice(&apples);
+        assert!(fruits
+            .iter()
+            .map(|fruit| fruit.entity())
+            .eq(expected.iter().copied()));
+
+        let capacity = apples.capacity();
+        let fruits: Vec<Instance<Fruit>> = Instance::cast_vec(apples);
+        assert_eq!(fruits.capacity(), capacity);
+        assert!(fruits.iter().map(|fruit| fruit.entity()).eq(expected));
+    }
+
+    #[test]
+    fn downcast_world() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = Or<(With<Apple>, With<Orange>)>;
+        }
+
+        kind!(Apple is Fruit);
+        kind!(Orange is Fruit);
+
+        let mut world = World::new();
+        let apple: Instance<Fruit> = world.spawn_instance(Apple).instance().cast_into();
+        let orange: Instance<Fruit> = world.spawn_instance(Orange).instance().cast_into();
+        assert_eq!(
+            apple.downcast_world::<Apple>(&world).map(|a| a.entity()),
+            Some(apple.entity())
+        );
+        assert!(orange.downcast_world::<Apple>(&world).is_none());
+    }
+
+    #[test]
+    fn test_support() {
+        use crate::test_support::{assert_kind, assert_not_kind, instances_of};
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        world.spawn(Orange);
+
+        assert_kind::<Apple>(&mut world, apple.entity());
+        assert_not_kind::<Orange>(&mut world, apple.entity());
+        assert_eq!(instances_of::<Apple>(&mut world), [apple]);
+    }
+
+    #[test]
+    #[should_panic(expected = "is not of kind Orange")]
+    fn test_support_assert_kind() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        crate::test_support::assert_kind::<Orange>(&mut world, apple.entity());
+    }
+
+    #[test]
+    fn test_support_spawn() {
+        use crate::test_support::{apply_and_get, spawn_instance_now};
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        let mut world = World::new();
+        let apple = spawn_instance_now(&mut world, Apple);
+        assert!(world.entity(apple.entity()).contains::<Apple>());
+
+        let apple = apply_and_get(&mut world, |commands| {
+            commands.spawn_instance(Apple).insert(Ripe).instance()
+        });
+        assert!(world.entity(apple.entity()).contains::<Ripe>());
+    }
+
+    #[test]
+    fn register_kind_reflect() {
+        use bevy_app::App;
+        use bevy_ecs::reflect::AppTypeRegistry;
+
+        #[derive(Component, TypePath)]
+        struct Apple;
+
+        let mut app = App::new();
+        app.add_plugins(KindPlugin).register_kind::<Apple>();
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let registration = registry
+            .get_with_short_type_path("Instance<Apple>")
+            .unwrap();
+        assert_eq!(
+            registration.type_info().type_path(),
+            Instance::<Apple>::type_path()
+        );
+        assert!(Instance::<Apple>::type_path().starts_with("moonshine_kind::instance::Instance<"));
+        assert!(registry.contains(std::any::TypeId::of::<Instance<Any>>()));
+    }
+
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn instance_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        #[derive(Component)]
+        struct Apple;
+
+        let bits = Entity::PLACEHOLDER.to_bits().to_le_bytes();
+        let apple = Instance::<Apple>::arbitrary(&mut Unstructured::new(&bits)).unwrap();
+        assert_eq!(apple, Instance::PLACEHOLDER);
+    }
+
+    #[test]
+    fn instance_placeholder() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        assert!(Instance::<Apple>::PLACEHOLDER.is_placeholder());
+        assert!(!apple.is_placeholder());
+    }
+
+    #[test]
+    fn send_instance_event() {
+        use bevy_ecs::event::Events;
+
+        #[derive(Component)]
+        struct Apple;
+
+        struct Bite(u32);
+
+        let mut world = World::new();
+        world.init_resource::<Events<InstanceEvent<Apple, Bite>>>();
+        let apple = world.spawn_instance(Apple).instance();
+        let other = world.spawn_empty().id();
+
+        let mut commands = world.commands();
+        commands.send_instance_event::<Apple, _>(apple.entity(), Bite(1));
+        commands.send_instance_event::<Apple, _>(other, Bite(2));
+        world.flush();
+        assert!(!world.send_instance_event::<Apple, _>(other, Bite(3)));
+        assert!(world.send_instance_event::<Apple, _>(apple.entity(), Bite(4)));
+
+        let events = world.resource::<Events<InstanceEvent<Apple, Bite>>>();
+        let events: Vec<_> = events
+            .iter_current_update_events()
+            .map(|event| (event.instance, event.event.0))
+            .collect();
+        assert_eq!(events, [(apple, 1), (apple, 4)]);
+    }
+
+    #[test]
+    fn instance_id() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        let id = apple.id();
+        assert_eq!(id.resolve(&world), Some(apple));
+
+        world.despawn(apple.entity());
+        let reused = world.spawn_instance(Apple).instance();
+        assert_eq!(reused.entity().index(), apple.entity().index());
+        assert_ne!(reused.id(), id);
+        assert_eq!(id.resolve(&world), None);
+        assert_eq!(InstanceId::<Apple>::from_bits(id.to_bits()), id);
+    }
+
+    #[test]
+    fn instance_parts_mut() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Freshness(u32);
+
+        #[derive(Component)]
+        struct Weight(u32);
+
+        let mut world = World::new();
+        let apple = world
+            .spawn_instance_entity(Apple)
+            .insert((Freshness(1), Weight(1)))
+            .instance();
+        world.clear_trackers();
+
+        let mut query = world.query::<InstancePartsMut<(Apple, Freshness, Weight)>>();
+        for mut item in query.iter_mut(&mut world) {
+            assert_eq!(item.instance(), apple);
+            let (_, freshness, _) = item.parts_mut();
+            freshness.0 = 2;
+        }
+
+        let mut query = world.query::<(Ref<Freshness>, Ref<Weight>)>();
+        let (freshness, weight) = query.single(&world);
+        assert_eq!((freshness.0, weight.0), (2, 1));
+        assert!(freshness.is_changed());
+        assert!(!weight.is_changed());
+    }
+
+    #[test]
+    fn entity_is_kind() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        #[derive(Component)]
+        struct Rotten;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = Or<(With<Apple>, With<Orange>)>;
+        }
+
+        type FreshApple = KindAnd<Apple, NotKind<Rotten>>;
+
+        let mut world = World::new();
+        let apple = world.spawn(Apple).id();
+
+        // Neither `Orange` nor `Rotten` are registered yet, so `Fruit` and `FreshApple` cannot be evaluated:
+        let entity = world.entity(apple);
+        assert!(!entity.is_kind::<Fruit>(&world));
+        assert!(!entity.is_kind::<FreshApple>(&world));
+
+        // `IsKindWorld` registers them:
+        let mut entity = world.entity_mut(apple);
+        assert!(entity.is_kind::<Fruit>());
+        assert!(entity.is_kind::<FreshApple>());
+
+        let entity = world.entity(apple);
+        assert!(entity.is_kind::<Fruit>(&world));
+        assert!(entity.is_kind::<FreshApple>(&world));
+
+        let rotten = world.spawn((Apple, Rotten)).id();
+        let orange = world.spawn(Orange).id();
+        assert!(!world.entity(rotten).is_kind::<FreshApple>(&world));
+        assert!(world.entity(orange).is_kind::<Fruit>(&world));
+        assert!(!world.entity(orange).is_kind::<Apple>(&world));
+
+        let mut entity = world.entity_mut(rotten);
+        assert!(entity.is_kind::<Fruit>());
+        assert!(!entity.is_kind::<FreshApple>());
+    }
+
+    #[test]
+    fn instance_commands_to_instance() {
+        #[derive(Component)]
+        struct Foo;
+
+        fn contained<T: Kind>(item: &impl ContainsInstance<T>) -> Instance<T> {
+            item.instance()
+        }
+
+        let mut world = World::new();
+        let entity = world.spawn(Foo).id();
+        let mut queue = bevy_ecs::world::CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        let foo = commands.instance(Instance::<Foo>::from_entity(world.entity(entity)).unwrap());
+        assert_eq!(foo.to_instance().entity(), entity);
+        assert_eq!(contained(&foo), foo.to_instance());
+    }
+
+    #[test]
+    fn spawn_instance_with() {
+        #[derive(Component)]
+        struct Foo;
+
+        #[derive(Component)]
+        struct Bar;
+
+        let mut world = World::new();
+        let foo = world
+            .run_system_once(|mut commands: Commands| {
+                commands.spawn_instance_with(Foo, |foo| {
+                    foo.insert(Bar);
+                })
+            })
+            .unwrap();
+        assert!(world.entity(foo.entity()).contains::<Bar>());
+
+        let foo = world.spawn_instance_with(Foo, |foo| {
+            foo.insert(Bar);
+        });
+        assert!(world.entity(foo.entity()).contains::<Bar>());
+    }
+
+    #[test]
+    fn log_components() {
+        #[derive(Component)]
+        struct Foo;
+
+        let mut world = World::new();
+        let foo = world.spawn_instance(Foo).instance();
+        let message = instance::components_message::<Foo>(&world, foo.entity()).unwrap();
+        assert!(message.starts_with(&format!("{foo:?} has components: [")));
+        assert!(message.contains("\"Foo\""));
+
+        let mut queue = bevy_ecs::world::CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.instance(foo).log_components().despawn();
+        commands.instance(foo).log_components();
+        queue.apply(&mut world);
+        assert!(instance::components_message::<Foo>(&world, foo.entity()).is_none());
+    }
+
+    #[test]
+    fn instance_children() {
+        use bevy_hierarchy::BuildChildren;
+
+        #[derive(Component)]
+        struct Tree;
+
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let tree = world.spawn_instance(Tree).instance();
+        let apple = world.spawn_instance(Apple).instance();
+        world.spawn(Tree);
+        world.entity_mut(tree.entity()).add_child(apple.entity());
+
+        let children: Vec<_> = world.instance_children::<Tree>().collect();
+        assert_eq!(children, [(tree, apple.cast_into_any())]);
+    }
+
+    #[test]
+    fn instance_mapper() {
+        use bevy_ecs::entity::{EntityHashMap, EntityMapper, MapEntities};
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component, Clone)]
+        struct Target(Instance<Apple>);
+
+        impl MapEntities for Target {
+            fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+                self.0.map_entities(entity_mapper);
+            }
+        }
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        let source = world.spawn(Target(apple)).id();
+        let clone = world.spawn_empty().id();
+
+        // Clone only `source`, so `apple` is not part of the clone set:
+        let mut map = EntityHashMap::default();
+        map.insert(source, clone);
+
+        let mut target = world.get::<Target>(source).unwrap().clone();
+        target.map_entities(&mut InstanceMapper::new(&map, UnmappedPolicy::Preserve));
+        assert_eq!(target.0, apple);
+
+        let mut target = world.get::<Target>(source).unwrap().clone();
+        target.map_entities(&mut InstanceMapper::new(&map, UnmappedPolicy::Placeholder));
+        assert!(target.0.is_placeholder());
+
+        // Clone both `source` and `apple`:
+        let apple_clone = world.spawn_instance(Apple).instance();
+        map.insert(apple.entity(), apple_clone.entity());
+
+        let mut target = world.get::<Target>(source).unwrap().clone();
+        target.map_entities(&mut InstanceMapper::new(&map, UnmappedPolicy::Placeholder));
+        assert_eq!(target.0, apple_clone);
+        world.entity_mut(clone).insert(target);
+        assert_eq!(world.get::<Target>(clone).unwrap().0, apple_clone);
+    }
+
+    #[test]
+    fn to_instance_set() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let a = world.spawn_instance(Apple).instance();
+        let b = world.spawn_instance(Apple).instance();
+
+        let mut set = world
+            .run_system_once(|apples: Query<Instance<Apple>>| apples.to_instance_set())
+            .unwrap();
+        assert_eq!(set, [a, b, a].into_iter().collect());
+        assert_eq!(set.len(), 2);
+
+        let mut drained: Vec<_> = set.drain().collect();
+        drained.sort();
+        assert_eq!(drained, [a, b]);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn get_single_instance() {
+        #[derive(Component)]
+        struct Apple;
+
+        fn single(world: &mut World) -> Result<Instance<Apple>, KindSingleError> {
+            world
+                .run_system_once(|apples: Query<Instance<Apple>>| apples.get_single_instance())
+                .unwrap()
+        }
+
+        let mut world = World::new();
+        let error = single(&mut world).unwrap_err();
+        assert_eq!(error.to_string(), "expected exactly one Apple, found none");
+
+        let apple = world.spawn_instance(Apple).instance();
+        assert_eq!(single(&mut world), Ok(apple));
+
+        world.spawn(Apple);
+        world.spawn(Apple);
+        let error = single(&mut world).unwrap_err();
+        assert_eq!(error.to_string(), "expected exactly one Apple, found 3");
+    }
+
+    #[test]
+    fn spawn_child_instances_world() {
+        #[derive(Component)]
+        struct Basket;
+
+        #[derive(Component)]
+        struct Apple(u32);
+
+        let mut world = World::new();
+        let basket = world.spawn_instance(Basket).instance();
+        let apples = world.spawn_child_instances(basket.entity(), (0..3).map(Apple));
+
+        let children = world.get::<Children>(basket.entity()).unwrap();
+        assert!(apples.iter().eq(children.iter()));
+        for (index, apple) in apples.iter().enumerate() {
+            assert_eq!(world.get::<Apple>(apple.entity()).unwrap().0, index as u32);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "does not exist")]
+    fn spawn_child_instances_world_invalid_parent() {
+        #[derive(Component)]
+        struct Basket;
+
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let basket = world.spawn_instance(Basket).instance();
+        world.despawn(basket.entity());
+        world.spawn_child_instances(basket.entity(), (0..3).map(|_| Apple));
+    }
+
+    #[test]
+    fn get_instance_commands() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        let mut world = World::new();
+        let entity = world.spawn(Apple).id();
+        world
+            .run_system_once(move |mut commands: Commands| {
+                commands.get_instance_commands::<Apple>(entity).insert(Ripe);
+            })
+            .unwrap();
+        assert!(world.entity(entity).contains::<Ripe>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_instance_commands_invalid() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let entity = world.spawn_empty().id();
+        world
+            .run_system_once(move |mut commands: Commands| {
+                commands.get_instance_commands::<Apple>(entity);
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn as_any_slice() {
+        #[derive(Component)]
+        struct Apple;
+
+        fn entities(instances: &[Instance<Any>]) -> Vec<Entity> {
+            instances.iter().map(|instance| instance.entity()).collect()
+        }
+
+        let mut world = World::new();
+        let apples: Vec<Instance<Apple>> = (0..3)
+            .map(|_| world.spawn_instance(Apple).instance())
+            .collect();
+        let any = Instance::as_any_slice(&apples);
+        assert_eq!(
+            entities(any),
+            apples
+                .iter()
+                .map(|apple| apple.entity())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn instance_in_hook() {
+        use bevy_ecs::{component::ComponentId, world::DeferredWorld};
+
+        #[derive(Component)]
+        #[component(on_add = on_add_apple)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = Or<(With<Apple>, With<Orange>)>;
+        }
+
+        #[derive(Resource, Default)]
+        struct Added(Vec<Instance<Apple>>, Vec<Instance<Fruit>>);
+
+        fn on_add_apple(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+            let apple = world.instance_in_hook::<Apple>(entity);
+            let fruit = world.instance_in_hook_kind::<Fruit>(entity).unwrap();
+            let mut added = world.resource_mut::<Added>();
+            added.0.push(apple);
+            added.1.push(fruit);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Added>();
+        world.register_component::<Orange>();
+        let apple = world.spawn(Apple).id();
+        let added = world.resource::<Added>();
+        assert_eq!(added.0, [apple]);
+        assert_eq!(added.1, [apple]);
+    }
+
+    #[test]
+    fn into_instance() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        let mut world = World::new();
+        let apple = world
+            .run_system_once(|mut commands: Commands| {
+                commands
+                    .spawn((Apple, Ripe))
+                    .into_instance::<Apple>()
+                    .instance()
+            })
+            .unwrap();
+        assert!(world.entity(apple.entity()).contains::<Ripe>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_instance_invalid() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        let mut world = World::new();
+        world
+            .run_system_once(|mut commands: Commands| {
+                commands.spawn(Ripe).into_instance::<Apple>();
+            })
+            .unwrap();
+    }
+
+    #[test]
+    fn partition_by_kind() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = Or<(With<Apple>, With<Orange>)>;
+        }
+
+        let mut world = World::new();
+        let a = world.spawn((Apple, Ripe)).id();
+        let b = world.spawn(Orange).id();
+        let c = world.spawn((Orange, Ripe)).id();
+        let fruits: Vec<Instance<Fruit>> = [a, b, c]
+            .into_iter()
+            .map(|entity| world.get_instance_kind(entity).unwrap())
+            .collect();
+
+        let (ripe, rest) = Instance::partition_by_kind::<Ripe>(&fruits, &world);
+        assert_eq!(ripe, [a, c]);
+        assert_eq!(rest, [b]);
+
+        #[derive(Component)]
+        struct Rotten;
+
+        world.register_component::<Rotten>();
+        let (fresh, rest) = Instance::partition_by_kind::<NotKind<Rotten>>(&fruits, &world);
+        assert_eq!(fresh, [a, b, c]);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn instance_world_mut() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        world.instance_world_mut(apple).unwrap().insert(Ripe);
+        assert!(world.entity(apple.entity()).contains::<Ripe>());
+
+        world.entity_mut(apple.entity()).remove::<Apple>();
+        assert!(world.instance_world_mut(apple).is_none());
+
+        world.despawn(apple.entity());
+        assert!(world.instance_world_mut(apple).is_none());
+    }
+
+    #[test]
+    fn children_of_kind() {
+        use bevy_hierarchy::{BuildChildren, ChildBuild};
+
+        #[derive(Component)]
+        struct Tree;
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Leaf;
+
+        let mut world = World::new();
+        let mut tree = world.spawn_instance_entity(Tree);
+        let mut apples = Vec::new();
+        tree.with_children(|tree| {
+            apples.push(tree.spawn(Apple).id());
+            tree.spawn(Leaf);
+            apples.push(tree.spawn(Apple).id());
+        });
+        assert_eq!(tree.children_of_kind::<Apple>(), apples);
+        let tree = tree.instance();
+        assert_eq!(tree.children_of_kind::<Apple>(&world), apples);
+        assert_eq!(
+            world.instance(tree).children_of_kind::<Apple>(&world),
+            apples
+        );
+
+        let empty = world.spawn_instance(Tree).instance();
+        assert!(empty.children_of_kind::<Apple>(&world).is_empty());
+    }
+
+    #[test]
+    fn try_narrow() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let apple = world.spawn(Apple).id();
+        let other = world.spawn_empty().id();
+
+        let any = Instance::<Any>::from(apple);
+        assert_eq!(any.try_narrow::<Apple>(world.entity(apple)).unwrap(), apple);
+        assert!(any.try_narrow::<Apple>(world.entity(other)).is_none());
+        assert!(Instance::<Any>::from(other)
+            .try_narrow::<Apple>(world.entity(other))
+            .is_none());
+    }
+
+    #[test]
+    fn instance_set_iter() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let a = world.spawn_instance(Apple).instance();
+        let b = world.spawn_instance(Apple).instance();
+        let c = world.spawn_instance(Apple).instance();
+
+        let mut set: InstanceSet<Apple> = [a].into_iter().collect();
+        set.extend([b, c]);
+        set.extend(InstanceSet::from_iter([a, b]));
+        assert_eq!(set.len(), 3);
+
+        let mut borrowed = Vec::new();
+        for apple in &set {
+            borrowed.push(apple);
+        }
+        borrowed.sort();
+        assert_eq!(borrowed, [a, b, c]);
+
+        let mut drained: Vec<_> = set.clone().drain().collect();
+        drained.sort();
+        assert_eq!(drained, [a, b, c]);
+
+        let mut owned = Vec::new();
+        for apple in set {
+            owned.push(apple);
+        }
+        owned.sort();
+        assert_eq!(owned, [a, b, c]);
+    }
+
+    #[test]
+    fn dyn_kind() {
+        use bevy_ecs::component::{ComponentDescriptor, StorageType};
+        use std::alloc::Layout;
+
+        let mut world = World::new();
+        // SAFE: Zero-sized components have no drop logic and are trivially `Send + Sync`.
+        let descriptor = unsafe {
+            ComponentDescriptor::new_with_layout(
+                "Apple",
+                StorageType::Table,
+                Layout::new::<()>(),
+                None,
+            )
+        };
+        let id = world.register_component_with_descriptor(descriptor);
+
+        let apple = world.spawn_empty().id();
+        let mut entity = world.entity_mut(apple);
+        bevy_ecs::ptr::OwningPtr::make((), |ptr| {
+            // SAFE: `Apple` is a zero-sized component with the same layout as `()`.
+            unsafe { entity.insert_by_id(id, ptr) };
+        });
+        let other = world.spawn_empty().id();
+
+        assert!(world.contains_dyn_kind(apple, id));
+        assert!(!world.contains_dyn_kind(other, id));
+        assert_eq!(world.iter_dyn_kind(id).collect::<Vec<_>>(), [apple]);
+
+        let instance = world.get_dyn_instance(apple, id).unwrap();
+        assert_eq!(instance.entity(), apple);
+        assert_eq!(instance.component_id(), id);
+        assert!(world.get_dyn_instance(other, id).is_none());
+    }
+
+    #[test]
+    fn instance_many() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let a = world.spawn_instance(Apple).instance();
+        let b = world.spawn_instance(Apple).instance();
+        let c = world.spawn_instance(Apple).instance();
+        world.despawn(c.entity());
+
+        let [x, y, z, w] = world.instance_many([a, b, a, c]);
+        assert_eq!(x.unwrap().instance(), a);
+        assert_eq!(y.unwrap().instance(), b);
+        assert_eq!(z.unwrap().instance(), a);
+        assert!(w.is_none());
+
+        let items = world.instance_many_slice(&[c, b]);
+        assert!(items[0].is_none());
+        assert_eq!(items[1].unwrap().instance(), b);
+    }
+
+    #[test]
+    fn get_instance_kind_deferred() {
+        use bevy_ecs::{component::ComponentId, world::DeferredWorld};
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        #[component(on_add = on_add_ripe)]
+        struct Ripe;
+
+        struct RipeFruit;
+
+        impl Kind for RipeFruit {
+            type Filter = (With<Apple>, With<Ripe>);
+        }
+
+        #[derive(Resource, Default)]
+        struct RipeFruits(Vec<Instance<RipeFruit>>);
+
+        fn on_add_ripe(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+            if let Some(fruit) = world.get_instance_kind::<RipeFruit>(entity) {
+                world.resource_mut::<RipeFruits>().0.push(fruit);
+            }
+        }
+
+        let mut world = World::new();
+        world.init_resource::<RipeFruits>();
+        let apple = world.spawn((Apple, Ripe)).id();
+        world.spawn(Ripe);
+        assert_eq!(world.resource::<RipeFruits>().0, [apple]);
+    }
+
+    #[test]
+    fn instance_mut_as_ref() {
+        #[derive(Component)]
+        struct Apple(u32);
+
+        fn count(apple: InstanceRef<Apple>) -> u32 {
+            apple.0
+        }
+
+        let mut world = World::new();
+        let entity = world.spawn(Apple(1)).id();
+        let mut apple = InstanceMutItem::<Apple>::from_entity(&mut world, entity).unwrap();
+        apple.0 += 1;
+        assert_eq!(count(apple.as_ref_instance()), 2);
+        assert_eq!(apple.as_ref_instance().instance(), apple.instance());
+    }
+
+    #[test]
+    fn trigger_instances() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        #[derive(Event)]
+        struct Rot;
+
+        #[derive(Resource, Default)]
+        struct Rotten(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Rotten>();
+        world.add_observer(|trigger: Trigger<Rot>, mut rotten: ResMut<Rotten>| {
+            rotten.0.push(trigger.entity());
+        });
+
+        let a = world.spawn(Apple).id();
+        let b = world.spawn(Apple).id();
+        world.spawn(Orange);
+
+        world.trigger_instances::<Apple, _>(Rot);
+        assert_eq!(world.resource::<Rotten>().0, [a, b]);
+
+        world.resource_mut::<Rotten>().0.clear();
+        world.despawn(a);
+        world.despawn(b);
+        world.commands().trigger_instances::<Apple, _>(Rot);
+        world.flush();
+        assert!(world.resource::<Rotten>().0.is_empty());
+
+        let c = world.spawn(Apple).id();
+        world.commands().trigger_instances::<Apple, _>(Rot);
+        world.flush();
+        assert_eq!(world.resource::<Rotten>().0, [c]);
+    }
+
+    #[test]
+    fn despawn_if() {
+        #[derive(Component)]
+        struct Apple(u32);
+
+        let mut world = World::new();
+        let a = world.spawn_instance(Apple(0)).instance();
+        let b = world.spawn_instance(Apple(1)).instance();
+        let c = world.spawn_instance(Apple(0)).instance();
+
+        let mut commands = world.commands();
+        commands.instance(a).despawn_if(|apple| apple.0 == 0);
+        commands.instance(b).despawn_if(|apple| apple.0 == 0);
+        commands.instance(c).despawn();
+        commands.instance(c).despawn_if(|_| true);
+        world.flush();
+
+        assert!(world.get_entity(a.entity()).is_err());
+        assert!(world.get_entity(b.entity()).is_ok());
+        assert!(world.get_entity(c.entity()).is_err());
+    }
+
+    #[test]
+    fn try_insert() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Fresh;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+
+        let mut commands = world.commands();
+        commands.instance(apple).despawn();
+        commands.instance(apple).try_insert(Fresh);
+        world.flush();
+
+        assert!(world.get_entity(apple.entity()).is_err());
+    }
+
+    #[test]
+    fn retain() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Fresh;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance_entity(Apple).insert(Fresh).instance();
+
+        world.commands().instance(apple).retain::<Apple>();
+        world.flush();
+
+        assert!(world.entity(apple.entity()).contains::<Apple>());
+        assert!(!world.entity(apple.entity()).contains::<Fresh>());
+    }
+
+    #[test]
+    fn despawn_instances() {
+        use bevy_hierarchy::BuildChildren;
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Orange;
+
+        let mut world = World::new();
+        let a = world.spawn_instance(Apple).instance();
+        let b = world.spawn_instance(Apple).instance();
+        let orange = world.spawn_instance(Orange).instance();
+        world.entity_mut(a.entity()).add_child(b.entity());
+        let seed = world.spawn_empty().set_parent(b.entity()).id();
+
+        world.commands().despawn_instances_recursive::<Apple>();
+        world.flush();
+
+        assert!(world.get_entity(a.entity()).is_err());
+        assert!(world.get_entity(b.entity()).is_err());
+        assert!(world.get_entity(seed).is_err());
+        assert!(world.get_entity(orange.entity()).is_ok());
+
+        world.commands().despawn_instances::<Orange>();
+        world.flush();
+        assert!(world.get_entity(orange.entity()).is_err());
+    }
+
+    #[test]
+    fn cast_into_component_invalid() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Ripe;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance_entity(Apple);
+        assert!(apple.cast_into_component::<Ripe>().is_none());
+    }
+
+    #[test]
+    fn spawn_child_instances() {
+        use bevy_hierarchy::Children;
+
+        #[derive(Component)]
+        struct Basket;
+
+        #[derive(Component)]
+        struct Apple(u32);
+
+        let mut world = World::new();
+        let mut commands = world.commands();
+        let mut basket = commands.spawn_instance(Basket);
+        let apples = basket.spawn_child_instances((0..3).map(Apple));
+        let basket = basket.instance();
+        world.flush();
+
+        let children = world.get::<Children>(basket.entity()).unwrap();
+        assert!(children
+            .iter()
+            .copied()
+            .eq(apples.iter().map(|apple| apple.entity())));
+        for (i, apple) in apples.iter().enumerate() {
+            assert_eq!(world.get::<Apple>(apple.entity()).unwrap().0, i as u32);
+        }
+    }
+
+    #[test]
+    fn with_child_instance() {
+        use bevy_hierarchy::{Children, Parent};
+
+        #[derive(Component)]
+        struct Tree;
+
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let mut apples = Vec::new();
+        let mut commands = world.commands();
+        let tree = commands
+            .spawn_instance(Tree)
+            .with_child_instance(Apple, |apple| apples.push(apple.instance()))
+            .with_child_instance(Apple, |apple| apples.push(apple.instance()))
+            .instance();
+        world.flush();
+
+        let children = world.get::<Children>(tree.entity()).unwrap();
+        assert!(children
+            .iter()
+            .copied()
+            .eq(apples.iter().map(|apple| apple.entity())));
+        for apple in apples {
+            assert_eq!(
+                world.get::<Parent>(apple.entity()).unwrap().get(),
+                tree.entity()
+            );
+        }
+    }
+
+    #[test]
+    fn contains_instance() {
+        #[derive(Component)]
+        struct Apple;
+
+        fn entity_of(instance: impl ContainsInstance<Apple>) -> Entity {
+            instance.instance().entity()
+        }
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple).instance();
+        assert_eq!(entity_of(apple), apple.entity());
+        assert_eq!(entity_of(world.instance(apple)), apple.entity());
+        assert_eq!(apple.any().entity(), apple.entity());
+    }
+
+    #[test]
+    fn instance_collections_debug() {
+        #[derive(Component)]
+        struct Apple;
+
+        struct Fruit;
+
+        impl Kind for Fruit {
+            type Filter = With<Apple>;
+
+            fn debug_name() -> String {
+                "Fruity".to_string()
+            }
+        }
+
+        kind!(Apple is Fruit);
+
+        let mut world = World::new();
+        let apple: Instance<Fruit> = world.spawn_instance(Apple).instance().cast_into();
+        let (index, generation) = (apple.entity().index(), apple.entity().generation());
+
+        let mut set = InstanceSet::new();
+        set.insert(apple);
+        assert_eq!(
+            format!("{set:?}"),
+            format!("InstanceSet<Fruity>[Fruity({index}v{generation})]")
+        );
+
+        let mut map = InstanceMap::new();
+        map.insert(apple, 1);
+        assert_eq!(
+            format!("{map:?}"),
+            format!("InstanceMap<Fruity>{{Fruity({index}v{generation}): 1}}")
+        );
+    }
+
+    #[test]
+    fn collect_instances_par() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let apples: Vec<Instance<Apple>> = (0..10_000)
+            .map(|_| world.spawn_instance(Apple).instance())
+            .collect();
+        world.spawn_empty();
+
+        let set = world
+            .run_system_once(|query: Query<Instance<Apple>>| query.collect_instances_par())
+            .unwrap();
+        assert_eq!(set.len(), apples.len());
+        assert!(apples.iter().all(|&apple| set.contains(apple)));
+    }
+
+    #[test]
+    fn despawn_instance_recursive() {
+        use bevy_hierarchy::BuildChildren;
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Seed;
+
+        let mut world = World::new();
+        let seed = world.spawn(Seed).id();
+        let mut apple = world.spawn_instance_entity(Apple);
+        apple.add_child(seed);
+        let apple = apple.instance();
+
+        assert!(world.despawn_instance_recursive(apple));
+        assert!(!world.despawn_instance_recursive(apple));
+        assert!(world.get_entity(seed).is_err());
+    }
+
+    #[test]
+    fn clone_instance() {
+        use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Apple(u32);
+
+        #[derive(Component)]
+        struct Seed;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Apple>();
+
+        let apple = world
+            .spawn_instance_entity(Apple(7))
+            .insert(Seed)
+            .instance();
+        let clone = world.clone_instance(apple).instance();
+        assert_ne!(apple, clone);
+        assert_eq!(world.instance(clone).0, 7);
+        assert!(!world.entity(clone.entity()).contains::<Seed>());
+
+        let clone = world.commands().instance(apple).clone_instance().instance();
+        world.flush();
+        assert_eq!(world.instance(clone).0, 7);
+    }
+
+    #[test]
+    fn clone_instance_without() {
+        use bevy_ecs::reflect::{AppTypeRegistry, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Rotten;
+
+        struct FreshApple;
+
+        impl Kind for FreshApple {
+            type Filter = (With<Apple>, Without<Rotten>);
+        }
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<Apple>();
+
+        let apple = world.spawn(Apple).id();
+        let apple = world
+            .get_instance_mut_kind::<FreshApple>(apple)
+            .unwrap()
+            .instance();
+        let clone = world.clone_instance(apple).instance();
+        assert!(world.entity(clone.entity()).contains::<Apple>());
+    }
+
+    #[test]
+    #[should_panic]
+    fn clone_instance_unregistered() {
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance_entity(Apple).instance();
+        world.clone_instance(apple);
+    }
+
+    #[test]
+    fn map_instances() {
+        use bevy_ecs::entity::{EntityHashMap, EntityMapper, MapEntities};
+
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Component)]
+        struct Basket {
+            apples: Vec<Instance<Apple>>,
+            pair: [Instance<Apple>; 2],
+            best: Option<Instance<Apple>>,
+        }
+
+        impl MapEntities for Basket {
+            fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+                super::map_instances(&mut self.apples, entity_mapper);
+                super::map_instances(&mut self.pair, entity_mapper);
+                super::map_instances(&mut self.best, entity_mapper);
+            }
+        }
+
+        struct Mapper(EntityHashMap<Entity>);
+
+        impl EntityMapper for Mapper {
+            fn map_entity(&mut self, entity: Entity) -> Entity {
+                self.0.get(&entity).copied().unwrap_or(entity)
+            }
+        }
+
+        let mut world = World::new();
+        let a = world.spawn_instance(Apple).instance();
+        let b = world.spawn_instance(Apple).instance();
+        let x = world.spawn_instance(Apple).instance();
+        let y = world.spawn_instance(Apple).instance();
+
+        let mut basket = Basket {
+            apples: vec![a, b, a],
+            pair: [b, a],
+            best: Some(b),
+        };
+
+        let mut mapper = Mapper([(*a, *x), (*b, *y)].into_iter().collect());
+        basket.map_entities(&mut mapper);
+        assert_eq!(basket.apples, [x, y, x]);
+        assert_eq!(basket.pair, [y, x]);
+        assert_eq!(basket.best, Some(y));
+    }
+
+    #[test]
+    fn observe_instance() {
+        #[derive(Component)]
+        struct Apple;
+
+        #[derive(Event)]
+        struct Gobble;
+
+        #[derive(Resource, Default)]
+        struct Gobbled(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Gobbled>();
+        let apple = world
+            .commands()
+            .spawn_instance(Apple)
+            .observe_instance(|trigger: Trigger<Gobble>, mut gobbled: ResMut<Gobbled>| {
+                gobbled.0.push(trigger.entity());
+            })
+            .instance();
+        let other = world.spawn(Apple).id();
+        world.flush();
+
+        world.trigger_targets(Gobble, [apple.entity(), other]);
+        assert_eq!(world.resource::<Gobbled>().0, [apple.entity()]);
+    }
+
+    #[test]
+    fn instance_ref_debug() {
+        #[derive(Component, Debug)]
+        struct Apple {
+            #[allow(dead_code)]
+            freshness: f32,
+        }
+
+        #[derive(Component, Debug)]
+        struct Seed(#[allow(dead_code)] u32);
+
+        #[derive(Component, Debug)]
+        struct Stem;
+
+        let mut world = World::new();
+        let apple = world.spawn_instance(Apple { freshness: 0.8 });
+        let expected = format!("{:?} Apple {{ freshness: 0.8 }}", apple.instance());
+        assert_eq!(format!("{apple:?}"), expected);
+        assert_eq!(format!("{:?}", apple.as_ref_instance()), expected);
+
+        let seed = world.spawn_instance(Seed(3));
+        assert_eq!(
+            format!("{seed:?}"),
+            format!("{:?} Seed(3)", seed.instance())
+        );
+
+        let stem = world.spawn_instance(Stem);
+        assert_eq!(format!("{stem:?}"), format!("{:?} Stem", stem.instance()));
+    }
+
+    #[test]
+    fn spawn_child_instance() {
+        use bevy_hierarchy::{Children, Parent};
+
+        #[derive(Component)]
+        struct Tree;
+
+        #[derive(Component)]
+        struct Apple;
+
+        let mut world = World::new();
+        let mut commands = world.commands();
+        let mut tree = commands.spawn_instance(Tree);
+        let apple = tree.spawn_child_instance(Apple).instance();
+        let tree = tree.instance();
+        world.flush();
+
+        assert_eq!(world.get::<Parent>(apple.entity()).unwrap().get(), *tree);
+        assert_eq!(**world.get::<Children>(tree.entity()).unwrap(), [*apple]);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn kind_cast_invalid() {
+        #[derive(Component)]
+        struct Foo;
+
+        #[derive(Component)]
+        struct Bar;
+
+        kind!(Foo is Bar);
+
+        let mut world = World::new();
+        world.register_component::<Bar>();
+        world.commands().spawn_instance(Foo).cast_into::<Bar>();
+        world.flush();
+    }
+
+    #[test]
+    fn kind_debug() {
+        mod inventory {
+            #[derive(bevy_ecs::component::Component)]
+            pub struct Item;
+        }
+
+        let item = Instance::<inventory::Item>::PLACEHOLDER;
+        let index = Entity::PLACEHOLDER.index();
+        let generation = Entity::PLACEHOLDER.generation();
+        assert_eq!(format!("{item:?}"), format!("Item({index}v{generation})"));
+        assert_eq!(
+            format!("{item:#?}"),
+            format!("{}({index}v{generation})", inventory::Item::type_path())
+        );
+        assert!(inventory::Item::type_path().ends_with("inventory::Item"));
     }
 }
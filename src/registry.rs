@@ -0,0 +1,146 @@
+use std::any::TypeId;
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::prelude::*;
+use bevy_reflect::TypePath;
+use bevy_utils::HashMap;
+
+use crate::{Any, Instance, Kind};
+
+/// A [`Resource`] which maps the [`TypeId`] of registered kinds to their names.
+///
+/// # Usage
+/// This registry is purely optional and is not required for normal usage of kinds.
+/// It is useful for diagnostics and tooling (such as inspectors) which need to identify a [`Kind`]
+/// at runtime without a generic parameter.
+///
+/// Use [`RegisterKind::register_kind`] to register a kind with an [`App`].
+///
+/// # Example
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, KindRegistry};
+///
+/// #[derive(Component, Reflect)]
+/// struct Apple;
+///
+/// let mut app = App::new();
+/// app.register_kind::<Apple>();
+///
+/// let registry = app.world().resource::<KindRegistry>();
+/// assert_eq!(registry.name_of(TypeId::of::<Apple>()), Some("Apple"));
+/// ```
+#[derive(Resource, Default)]
+pub struct KindRegistry {
+    kinds: HashMap<TypeId, KindInfo>,
+}
+
+impl KindRegistry {
+    /// Registers [`Kind`] `T` with this registry.
+    pub fn register<T: Kind>(&mut self) {
+        self.kinds
+            .entry(TypeId::of::<T>())
+            .or_insert_with(KindInfo::of::<T>);
+    }
+
+    /// Returns `true` if [`Kind`] `T` is registered with this registry.
+    pub fn contains<T: Kind>(&self) -> bool {
+        self.kinds.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Returns the [`KindInfo`] of the kind with the given [`TypeId`], if it is registered.
+    pub fn get(&self, type_id: TypeId) -> Option<&KindInfo> {
+        self.kinds.get(&type_id)
+    }
+
+    /// Returns the [debug name](Kind::debug_name) of the kind with the given [`TypeId`], if it is registered.
+    pub fn name_of(&self, type_id: TypeId) -> Option<&str> {
+        self.get(type_id).map(KindInfo::name)
+    }
+
+    /// Returns an iterator over all registered kinds.
+    pub fn iter(&self) -> impl Iterator<Item = &KindInfo> {
+        self.kinds.values()
+    }
+}
+
+/// Runtime information about a registered [`Kind`].
+///
+/// See [`KindRegistry`] for more information.
+#[derive(Clone, Debug)]
+pub struct KindInfo {
+    type_id: TypeId,
+    name: String,
+    type_path: &'static str,
+}
+
+impl KindInfo {
+    /// Returns the [`KindInfo`] of [`Kind`] `T`.
+    pub fn of<T: Kind>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            name: T::debug_name(),
+            type_path: T::type_path(),
+        }
+    }
+
+    /// Returns the [`TypeId`] of this kind.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Returns the [debug name](Kind::debug_name) of this kind.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the [type path](Kind::type_path) of this kind.
+    pub fn type_path(&self) -> &'static str {
+        self.type_path
+    }
+}
+
+/// A [`Plugin`] which initializes the [`KindRegistry`] and registers [`Instance<Any>`] for reflection.
+///
+/// # Usage
+/// This plugin is entirely optional. Kinds may be registered using [`RegisterKind::register_kind`] with or without it.
+/// It is only useful as a single place to opt into kind metadata and reflection.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use moonshine_kind::{prelude::*, KindRegistry};
+///
+/// #[derive(Component, Reflect)]
+/// struct Apple;
+///
+/// let mut app = App::new();
+/// app.add_plugins(KindPlugin).register_kind::<Apple>();
+/// assert!(app.world().resource::<KindRegistry>().contains::<Apple>());
+/// ```
+pub struct KindPlugin;
+
+impl Plugin for KindPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<KindRegistry>().register_kind::<Any>();
+    }
+}
+
+/// Extension trait to register a [`Kind`] with an [`App`].
+pub trait RegisterKind {
+    /// Registers [`Kind`] `T` with the [`KindRegistry`] and registers [`Instance<T>`] for reflection.
+    ///
+    /// The registry is initialized if it does not already exist.
+    /// The kind must implement [`TypePath`] so that the reflected type path of [`Instance<T>`] is stable.
+    fn register_kind<T: Kind + TypePath>(&mut self) -> &mut Self;
+}
+
+impl RegisterKind for App {
+    fn register_kind<T: Kind + TypePath>(&mut self) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_init::<KindRegistry>()
+            .register::<T>();
+        self.register_type::<Instance<T>>()
+    }
+}
@@ -0,0 +1,77 @@
+//! Utilities for testing kind semantics.
+//!
+//! This module is only available with the `test-support` feature.
+
+use bevy_ecs::{prelude::*, world::CommandQueue};
+
+use crate::{Instance, Kind, KindBundle};
+
+/// Asserts that the given [`Entity`] is of [`Kind`] `T`.
+///
+/// # Panics
+/// If the entity does not exist or does not match the filter of kind `T`.
+#[track_caller]
+pub fn assert_kind<T: Kind>(world: &mut World, entity: Entity) {
+    assert!(
+        world.get_entity(entity).is_ok(),
+        "{entity} does not exist and cannot be of kind {}",
+        T::debug_name()
+    );
+    crate::register_kind_components::<T>(world);
+    assert!(
+        crate::is_kind::<T>(world, entity),
+        "{entity} is not of kind {}",
+        T::debug_name()
+    );
+}
+
+/// Asserts that the given [`Entity`] is not of [`Kind`] `T`.
+///
+/// # Panics
+/// If the entity exists and matches the filter of kind `T`.
+#[track_caller]
+pub fn assert_not_kind<T: Kind>(world: &mut World, entity: Entity) {
+    crate::register_kind_components::<T>(world);
+    assert!(
+        !crate::is_kind::<T>(world, entity),
+        "{entity} is of kind {}",
+        T::debug_name()
+    );
+}
+
+/// Returns all instances of [`Kind`] `T` in the given [`World`].
+pub fn instances_of<T: Kind>(world: &mut World) -> Vec<Instance<T>> {
+    world.query::<Instance<T>>().iter(world).collect()
+}
+
+/// Spawns a new instance using its associated [`KindBundle`] immediately and returns it.
+///
+/// # Panics
+/// If the spawned entity does not match the filter of its kind.
+#[track_caller]
+pub fn spawn_instance_now<T: KindBundle>(world: &mut World, bundle: T) -> Instance<T::Kind> {
+    let entity = world.spawn(bundle).id();
+    assert_kind::<T::Kind>(world, entity);
+    // SAFE: `entity` is validated to be of kind `T::Kind`.
+    unsafe { Instance::from_entity_unchecked(entity) }
+}
+
+/// Runs the given function with [`Commands`] for the given [`World`], applies all queued commands,
+/// and returns the resulting instance.
+///
+/// # Usage
+/// This is useful to test commands which spawn or modify instances without a full [`App`](bevy_app::App) update.
+///
+/// # Panics
+/// If the returned instance is not of [`Kind`] `T` after all commands are applied.
+#[track_caller]
+pub fn apply_and_get<T: Kind>(
+    world: &mut World,
+    f: impl FnOnce(&mut Commands) -> Instance<T>,
+) -> Instance<T> {
+    let mut queue = CommandQueue::default();
+    let instance = f(&mut Commands::new(&mut queue, world));
+    queue.apply(world);
+    assert_kind::<T>(world, instance.entity());
+    instance
+}